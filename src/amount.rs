@@ -0,0 +1,423 @@
+use crate::ReadError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// How much of an item a recipe needs, e.g. "2 cups, chopped".
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Amount {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preparation: Option<String>,
+}
+
+// (unit, plural, other ways of writing it)
+const UNITS: &[(&str, &str, &[&str])] = &[
+    ("tsp", "tsp", &["tsps", "teaspoon", "teaspoons"]),
+    (
+        "tbsp",
+        "tbsp",
+        &["tbsps", "tbs", "tablespoon", "tablespoons"],
+    ),
+    ("cup", "cups", &[]),
+    (
+        "ml",
+        "ml",
+        &["milliliter", "milliliters", "millilitre", "millilitres"],
+    ),
+    ("l", "l", &["liter", "liters", "litre", "litres"]),
+    ("g", "g", &["gram", "grams"]),
+    ("kg", "kg", &["kilogram", "kilograms"]),
+    ("oz", "oz", &["ounce", "ounces"]),
+    ("lb", "lb", &["lbs", "pound", "pounds"]),
+    ("clove", "cloves", &[]),
+    ("can", "cans", &[]),
+    ("pinch", "pinches", &[]),
+    ("bunch", "bunches", &[]),
+    ("slice", "slices", &[]),
+];
+
+/// The standard spelling of a unit, if `word` is one.
+pub fn parse_unit(word: &str) -> Option<&'static str> {
+    let word = word.to_lowercase();
+    let word = word.trim_end_matches('.');
+    UNITS
+        .iter()
+        .find(|(unit, plural, others)| *unit == word || *plural == word || others.contains(&word))
+        .map(|(unit, _, _)| *unit)
+}
+
+fn plural(unit: &str) -> &str {
+    UNITS
+        .iter()
+        .find(|(u, _, _)| *u == unit)
+        .map(|(_, plural, _)| *plural)
+        .unwrap_or(unit)
+}
+
+/// Reads "2", "1.5", "1/2" or "1-1/2" as a number.
+pub fn parse_quantity(word: &str) -> Option<f64> {
+    if let Some((whole, fraction)) = word.split_once('-') {
+        return Some(parse_quantity(whole)? + parse_quantity(fraction)?);
+    }
+    let quantity = match word.split_once('/') {
+        Some((numerator, denominator)) => {
+            numerator.parse::<f64>().ok()? / denominator.parse::<f64>().ok()?
+        }
+        None => word.parse::<f64>().ok()?,
+    };
+    Some(quantity).filter(|q| q.is_finite() && *q > 0.0)
+}
+
+/// Writes a quantity the way a recipe would, e.g. "1 1/2" or "0.3".
+pub fn format_quantity(quantity: f64) -> String {
+    const FRACTIONS: [(f64, &str); 5] = [
+        (0.25, "1/4"),
+        (1.0 / 3.0, "1/3"),
+        (0.5, "1/2"),
+        (2.0 / 3.0, "2/3"),
+        (0.75, "3/4"),
+    ];
+    let whole = quantity.trunc();
+    let part = quantity - whole;
+    if part < 0.01 {
+        return format!("{}", whole);
+    }
+    if let Some((_, fraction)) = FRACTIONS.iter().find(|(f, _)| (part - f).abs() < 0.01) {
+        return if whole == 0.0 {
+            fraction.to_string()
+        } else {
+            format!("{} {}", whole, fraction)
+        };
+    }
+    let rounded = format!("{:.2}", quantity);
+    rounded
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+impl Amount {
+    pub fn is_empty(&self) -> bool {
+        self.quantity.is_none() && self.unit.is_none() && self.preparation.is_none()
+    }
+
+    /// This amount multiplied by `factor`, e.g. doubled for twice the servings.
+    pub fn scaled(&self, factor: f64) -> Amount {
+        Amount {
+            quantity: self.quantity.map(|quantity| quantity * factor),
+            ..self.clone()
+        }
+    }
+}
+
+impl FromStr for Amount {
+    type Err = ReadError;
+
+    /// Reads a quantity and an optional unit, e.g. "2 cans" or "1/2 cup".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let quantity = match words.first().and_then(|w| parse_quantity(w)) {
+            Some(quantity) if words.len() <= 2 => quantity,
+            _ => return Err(ReadError::ParseInputError),
+        };
+        let unit = words.get(1).map(|w| {
+            parse_unit(w)
+                .map(String::from)
+                .unwrap_or_else(|| w.to_lowercase())
+        });
+        Ok(Amount {
+            quantity: Some(quantity),
+            unit,
+            preparation: None,
+        })
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut how_much = vec![];
+        if let Some(quantity) = self.quantity {
+            how_much.push(format_quantity(quantity));
+        }
+        if let Some(unit) = &self.unit {
+            match self.quantity {
+                Some(quantity) if quantity > 1.0 => how_much.push(plural(unit).to_string()),
+                _ => how_much.push(unit.clone()),
+            }
+        }
+        let mut parts = vec![];
+        if !how_much.is_empty() {
+            parts.push(how_much.join(" "));
+        }
+        if let Some(preparation) = &self.preparation {
+            parts.push(preparation.clone());
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+// Units that convert into one another, smallest first, with how many of the
+// smallest unit each one is
+const CONVERSIONS: &[&[(&str, f64)]] = &[
+    &[("tsp", 1.0), ("tbsp", 3.0), ("cup", 48.0)],
+    &[("g", 1.0), ("kg", 1000.0)],
+    &[("ml", 1.0), ("l", 1000.0)],
+    &[("oz", 1.0), ("lb", 16.0)],
+];
+
+// how many of the smallest unit it converts to `unit` is, and the units it converts to
+fn conversion(unit: &str) -> Option<(f64, &'static [(&'static str, f64)])> {
+    CONVERSIONS.iter().find_map(|units| {
+        units
+            .iter()
+            .find(|(u, _)| *u == unit)
+            .map(|(_, factor)| (*factor, *units))
+    })
+}
+
+/// `quantity` of `from` as a quantity of `to`, if the one converts into the other.
+pub fn convert(quantity: f64, from: Option<&str>, to: Option<&str>) -> Option<f64> {
+    if from == to {
+        return Some(quantity);
+    }
+    let (from, units) = conversion(from?)?;
+    let (to, to_units) = conversion(to?)?;
+    std::ptr::eq(units, to_units).then(|| quantity * from / to)
+}
+
+/// Amounts added together. Quantities in units that convert into one
+/// another are summed, anything else is kept apart, e.g. "1 cup + 2 cloves".
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Total(Vec<(f64, Option<String>)>);
+
+impl Total {
+    /// Adds the quantity of `amount`, if it has one.
+    pub fn add(&mut self, amount: &Amount) {
+        let quantity = match amount.quantity {
+            Some(quantity) => quantity,
+            None => return,
+        };
+        let unit = amount.unit.as_deref();
+        for (total, total_unit) in self.0.iter_mut() {
+            if total_unit.as_deref() == unit {
+                *total += quantity;
+                return;
+            }
+            // sums are kept in the largest unit that went into them
+            if let (Some((from, units)), Some((to, to_units))) = (
+                unit.and_then(conversion),
+                total_unit.as_deref().and_then(conversion),
+            ) {
+                if std::ptr::eq(units, to_units) {
+                    if from > to {
+                        *total = *total * to / from + quantity;
+                        *total_unit = unit.map(String::from);
+                    } else {
+                        *total += quantity * from / to;
+                    }
+                    return;
+                }
+            }
+        }
+        self.0.push((quantity, unit.map(String::from)));
+    }
+
+    /// Takes the quantity of `amount` away from the part of the total in
+    /// a unit it converts to, dropping that part once nothing is left.
+    pub fn subtract(&mut self, amount: &Amount) {
+        let quantity = match amount.quantity {
+            Some(quantity) => quantity,
+            None => return,
+        };
+        let unit = amount.unit.as_deref();
+        let part = self
+            .0
+            .iter_mut()
+            .enumerate()
+            .find_map(|(i, (total, total_unit))| {
+                convert(quantity, unit, total_unit.as_deref()).map(|quantity| (i, total, quantity))
+            });
+        if let Some((i, total, quantity)) = part {
+            *total -= quantity;
+            if *total < 0.01 {
+                self.0.remove(i);
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Each part of the total as an amount of its own.
+    pub fn amounts(&self) -> Vec<Amount> {
+        self.0
+            .iter()
+            .map(|(quantity, unit)| Amount {
+                quantity: Some(*quantity),
+                unit: unit.clone(),
+                preparation: None,
+            })
+            .collect()
+    }
+}
+
+// the largest unit, up to `unit`, that gives a quantity a recipe would write
+fn readable(quantity: f64, unit: &str) -> (f64, String) {
+    let (factor, units) = match conversion(unit) {
+        Some(conversion) => conversion,
+        None => return (quantity, unit.to_string()),
+    };
+    let smallest = (quantity * factor, units[0].0.to_string());
+    units
+        .iter()
+        .rev()
+        .filter(|(_, f)| *f <= factor)
+        .map(|(u, f)| (quantity * factor / f, u.to_string()))
+        .find(|(quantity, _)| !format_quantity(*quantity).contains('.'))
+        .unwrap_or(smallest)
+}
+
+impl fmt::Display for Total {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let amounts: Vec<String> = self
+            .0
+            .iter()
+            .map(|(quantity, unit)| {
+                let (quantity, unit) = match unit {
+                    Some(unit) => {
+                        let (quantity, unit) = readable(*quantity, unit);
+                        (quantity, Some(unit))
+                    }
+                    None => (*quantity, None),
+                };
+                Amount {
+                    quantity: Some(quantity),
+                    unit,
+                    preparation: None,
+                }
+                .to_string()
+            })
+            .collect();
+        write!(f, "{}", amounts.join(" + "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display_amount() {
+        let amount = |quantity, unit: Option<&str>, preparation: Option<&str>| Amount {
+            quantity,
+            unit: unit.map(String::from),
+            preparation: preparation.map(String::from),
+        };
+        assert_eq!(
+            amount(Some(2.0), Some("cup"), Some("chopped")).to_string(),
+            "2 cups, chopped"
+        );
+        assert_eq!(amount(Some(0.5), Some("tsp"), None).to_string(), "1/2 tsp");
+        assert_eq!(
+            amount(Some(1.5), Some("can"), None).to_string(),
+            "1 1/2 cans"
+        );
+        assert_eq!(amount(Some(3.0), None, None).to_string(), "3");
+        assert_eq!(amount(None, None, Some("to taste")).to_string(), "to taste");
+        assert_eq!(amount(Some(0.3), Some("kg"), None).to_string(), "0.3 kg");
+    }
+
+    #[test]
+    fn test_parse_quantity_and_unit() -> Result<(), ReadError> {
+        assert_eq!(parse_quantity("2"), Some(2.0));
+        assert_eq!(parse_quantity("1/4"), Some(0.25));
+        assert_eq!(parse_quantity("1-1/2"), Some(1.5));
+        assert_eq!(parse_quantity("0"), None);
+        assert_eq!(parse_quantity("1/0"), None);
+        assert_eq!(parse_quantity("eggs"), None);
+        assert_eq!(parse_unit("Tablespoons"), Some("tbsp"));
+        assert_eq!(parse_unit("cups"), Some("cup"));
+        assert_eq!(parse_unit("oz."), Some("oz"));
+        assert_eq!(parse_unit("tomatoes"), None);
+        assert_eq!(
+            "2 Cans".parse::<Amount>()?,
+            Amount {
+                quantity: Some(2.0),
+                unit: Some("can".to_string()),
+                preparation: None,
+            }
+        );
+        assert_eq!("3 jars".parse::<Amount>()?.unit.as_deref(), Some("jars"));
+        assert!("some".parse::<Amount>().is_err());
+        assert!("2 cans tomatoes".parse::<Amount>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_total() {
+        let amount = |quantity, unit: &str| Amount {
+            quantity: Some(quantity),
+            unit: Some(unit.to_string()).filter(|u| !u.is_empty()),
+            preparation: None,
+        };
+        let total = |amounts: &[Amount]| {
+            let mut total = Total::default();
+            amounts.iter().for_each(|a| total.add(a));
+            total.to_string()
+        };
+        assert_eq!(total(&[amount(3.0, "tbsp"), amount(4.0, "tbsp")]), "7 tbsp");
+        assert_eq!(total(&[amount(0.5, "cup"), amount(8.0, "tbsp")]), "1 cup");
+        assert_eq!(
+            total(&[amount(1.0, "tbsp"), amount(1.0, "tsp")]),
+            "1 1/3 tbsp"
+        );
+        assert_eq!(total(&[amount(500.0, "g"), amount(1.0, "kg")]), "1 1/2 kg");
+        assert_eq!(total(&[amount(250.0, "ml"), amount(0.5, "l")]), "3/4 l");
+        assert_eq!(total(&[amount(0.5, "cup")]), "1/2 cup");
+        assert_eq!(total(&[amount(0.3, "kg")]), "300 g");
+        assert_eq!(
+            total(&[
+                amount(2.0, ""),
+                amount(1.0, "cup"),
+                amount(1.0, ""),
+                amount(2.0, "clove")
+            ]),
+            "3 + 1 cup + 2 cloves"
+        );
+        // amounts without a quantity don't add anything
+        assert!(Total::default().is_empty());
+        let mut to_taste = Total::default();
+        to_taste.add(&Amount {
+            preparation: Some("to taste".to_string()),
+            ..Amount::default()
+        });
+        assert!(to_taste.is_empty());
+    }
+
+    #[test]
+    fn test_subtract() {
+        let amount = |quantity, unit: &str| Amount {
+            quantity: Some(quantity),
+            unit: Some(unit.to_string()).filter(|u| !u.is_empty()),
+            preparation: None,
+        };
+        let mut total = Total::default();
+        total.add(&amount(1.0, "cup"));
+        total.add(&amount(2.0, "clove"));
+        total.subtract(&amount(4.0, "tbsp"));
+        assert_eq!(total.to_string(), "3/4 cup + 2 cloves");
+        // nothing to take it from
+        total.subtract(&amount(1.0, "kg"));
+        assert_eq!(total.to_string(), "3/4 cup + 2 cloves");
+        total.subtract(&amount(5.0, "clove"));
+        assert_eq!(total.to_string(), "3/4 cup");
+        assert_eq!(convert(2.0, Some("lb"), Some("oz")), Some(32.0));
+        assert_eq!(convert(2.0, Some("lb"), Some("cup")), None);
+        assert_eq!(convert(2.0, None, None), Some(2.0));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::ReadError;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BACKUPS: usize = 5;
+
+const BACKUP_DIR: &str = ".backups";
+
+fn backup_dir(path: &Path) -> PathBuf {
+    path.with_file_name(BACKUP_DIR)
+}
+
+fn backup_prefix(path: &Path) -> Result<String, ReadError> {
+    let file_name = path.file_name().ok_or(ReadError::ParseInputError)?;
+    Ok(format!("{}.", file_name.to_string_lossy()))
+}
+
+/// Copies `path` into the backup directory next to it under a timestamped
+/// name, then deletes all but the `keep` most recent backups of that file.
+pub fn backup<P: AsRef<Path>>(path: P, keep: usize) -> Result<Option<PathBuf>, ReadError> {
+    let path = path.as_ref();
+    if keep == 0 || !path.exists() {
+        return Ok(None);
+    }
+    let dir = backup_dir(path);
+    fs::create_dir_all(&dir)?;
+
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S%.6f");
+    let backup = dir.join(format!("{}{}", backup_prefix(path)?, timestamp));
+    crate::helpers::write_bytes(&backup, &fs::read(path)?)?;
+
+    for old in backups(path)?.into_iter().skip(keep) {
+        fs::remove_file(old)?;
+    }
+    Ok(Some(backup))
+}
+
+/// Backups of `path`, most recent first.
+pub fn backups<P: AsRef<Path>>(path: P) -> Result<Vec<PathBuf>, ReadError> {
+    let path = path.as_ref();
+    let dir = backup_dir(path);
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let prefix = backup_prefix(path)?;
+
+    let mut backups = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with(&prefix) {
+            backups.push(entry.path());
+        }
+    }
+    backups.sort();
+    backups.reverse();
+    Ok(backups)
+}
+
+/// Puts `backup` back in place of `path`, first backing up what is there now
+/// so that a restore can itself be undone.
+pub fn restore<P: AsRef<Path>, Q: AsRef<Path>>(
+    path: P,
+    backup: Q,
+    keep: usize,
+) -> Result<(), ReadError> {
+    let contents = fs::read(backup)?;
+    self::backup(&path, keep)?;
+    crate::helpers::write_bytes(path, &contents)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use assert_fs::prelude::*;
+
+    #[test]
+    fn test_backups_rotate() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = assert_fs::TempDir::new()?;
+        let file = dir.child("groceries.json");
+        assert_eq!(backup(file.path(), 2)?, None);
+
+        for contents in ["one", "two", "three"] {
+            file.write_str(contents)?;
+            backup(file.path(), 2)?;
+        }
+        let saved = backups(file.path())?;
+        assert_eq!(saved.len(), 2);
+        assert_eq!(fs::read_to_string(&saved[0])?, "three");
+        assert_eq!(fs::read_to_string(&saved[1])?, "two");
+
+        // other files' backups are left alone
+        dir.child("list.json").write_str("list")?;
+        backup(dir.child("list.json").path(), 2)?;
+        assert_eq!(backups(file.path())?.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_restore() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = assert_fs::TempDir::new()?;
+        let file = dir.child("groceries.json");
+        file.write_str("old")?;
+        let old = backup(file.path(), 5)?.unwrap();
+        file.write_str("new")?;
+
+        restore(file.path(), &old, 5)?;
+        file.assert("old");
+        // the contents we replaced were backed up
+        assert_eq!(fs::read_to_string(&backups(file.path())?[0])?, "new");
+        Ok(())
+    }
+}
@@ -1,10 +1,42 @@
-use clap::{Arg, Command};
+use crate::Config;
+use clap::{Arg, ArgMatches, Command};
 
-pub fn cli() -> Command<'static> {
+/// The words given for a multi-word argument, e.g. a recipe name, as one string.
+pub(crate) fn joined(matches: &ArgMatches, id: &str) -> String {
+    matches
+        .values_of(id)
+        .expect("argument is required")
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn cli(config: &Config) -> Command<'_> {
     Command::new("grusterylist")
         .about("Makes grocery lists")
         .subcommand_required(true)
         .arg_required_else_help(true)
+        .arg(
+            Arg::with_name("store")
+                .long("store")
+                .takes_value(true)
+                .possible_values(["json", "sqlite"])
+                .default_value(&config.store)
+                .help("Provides kind of storage for library and shopping list"),
+        )
+        .arg(
+            Arg::with_name("db path")
+                .long("db-path")
+                .takes_value(true)
+                .default_value(&config.database)
+                .help("Provides path for SQLite database"),
+        )
+        .arg(
+            Arg::with_name("backups")
+                .long("backups")
+                .takes_value(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Provides number of backups to keep of each saved file"),
+        )
         .subcommand(
             Command::new("recipes")
                 .about("Manages recipes library")
@@ -28,7 +60,14 @@ pub fn cli() -> Command<'static> {
                                 .required(true)
                                 .takes_value(true)
                                 .multiple_values(true)
-                                .help("Provides name of recipe to be added"),
+                                .help("Provides ingredients, e.g. \"2 cups tomatoes; chopped\""),
+                        )
+                        .arg(
+                            Arg::with_name("section")
+                                .long("section")
+                                .takes_value(true)
+                                .multiple_occurrences(true)
+                                .help("Provides \"item=section\" for each new ingredient"),
                         ),
                 )
                 .subcommand(
@@ -46,6 +85,8 @@ pub fn cli() -> Command<'static> {
                 .subcommand(
                     Command::new("edit")
                         .about("Edits recipes in library")
+                        .subcommand_required(true)
+                        .subcommand_precedence_over_arg(true)
                         .arg(
                             Arg::with_name("recipe")
                                 .long("recipe")
@@ -75,7 +116,7 @@ pub fn cli() -> Command<'static> {
                                         .required(true)
                                         .takes_value(true)
                                         .multiple_values(true)
-                                        .help("Provides name of ingredient to be added"),
+                                        .help("Provides ingredient to be added, e.g. \"2 cups kale; torn\""),
                                 ),
                         )
                         .subcommand(
@@ -88,6 +129,93 @@ pub fn cli() -> Command<'static> {
                                         .takes_value(true)
                                         .multiple_values(true)
                                         .help("Provides name of ingredient to be edited"),
+                                )
+                                .arg(
+                                    Arg::with_name("new")
+                                        .long("new")
+                                        .required(true)
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .help("Provides ingredient to use instead, with an optional amount"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("set")
+                                .about("Sets details of a recipe, an empty value or 0 clears one")
+                                .arg_required_else_help(true)
+                                .arg(
+                                    Arg::with_name("servings")
+                                        .long("servings")
+                                        .takes_value(true)
+                                        .value_parser(clap::value_parser!(u32))
+                                        .help("Provides number of servings recipe makes"),
+                                )
+                                .arg(
+                                    Arg::with_name("prep time")
+                                        .long("prep-time")
+                                        .takes_value(true)
+                                        .value_parser(clap::value_parser!(u32))
+                                        .help("Provides preparation time in minutes"),
+                                )
+                                .arg(
+                                    Arg::with_name("cook time")
+                                        .long("cook-time")
+                                        .takes_value(true)
+                                        .value_parser(clap::value_parser!(u32))
+                                        .help("Provides cooking time in minutes"),
+                                )
+                                .arg(
+                                    Arg::with_name("tags")
+                                        .long("tags")
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .min_values(0)
+                                        .help("Provides tags to replace recipe's tags with"),
+                                )
+                                .arg(
+                                    Arg::with_name("source")
+                                        .long("source")
+                                        .takes_value(true)
+                                        .help("Provides URL or book recipe comes from"),
+                                )
+                                .arg(
+                                    Arg::with_name("notes")
+                                        .long("notes")
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .min_values(0)
+                                        .help("Provides notes about recipe"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("add-step")
+                                .about("Adds a step to a recipe's instructions")
+                                .arg(
+                                    Arg::with_name("step")
+                                        .long("step")
+                                        .required(true)
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .help("Provides instruction to be added"),
+                                )
+                                .arg(
+                                    Arg::with_name("at")
+                                        .long("at")
+                                        .takes_value(true)
+                                        .value_parser(clap::value_parser!(usize))
+                                        .help("Provides number of step to insert before, otherwise adds it at the end"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("delete-step")
+                                .about("Deletes a step from a recipe's instructions")
+                                .arg(
+                                    Arg::with_name("step")
+                                        .long("step")
+                                        .required(true)
+                                        .takes_value(true)
+                                        .value_parser(clap::value_parser!(usize))
+                                        .help("Provides number of step to be deleted"),
                                 ),
                         ),
                 )
@@ -96,7 +224,7 @@ pub fn cli() -> Command<'static> {
                     Arg::with_name("path")
                         .long("path")
                         .takes_value(true)
-                        .default_value("groceries.json")
+                        .default_value(&config.library)
                         .help("Provides path for groceries library"),
                 )
                 .arg(
@@ -109,30 +237,591 @@ pub fn cli() -> Command<'static> {
         .subcommand(
             Command::new("groceries")
                 .about("Manages groceries library")
-                .subcommand(Command::new("add").about("Adds grocery items to library"))
+                .subcommand(
+                    Command::new("add")
+                        .about("Adds grocery items to library, asking for them unless given --name and --section")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item to be added")
+                                .requires("section"),
+                        )
+                        .arg(
+                            Arg::with_name("section")
+                                .long("section")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides section of item to be added")
+                                .requires("name"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("delete")
+                        .about("Deletes an item from library")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item to be deleted"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("rename")
+                        .about("Renames an item, in its recipes and on the saved list too")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item to be renamed"),
+                        )
+                        .arg(
+                            Arg::with_name("new")
+                                .long("new")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides new name of item"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("move")
+                        .about("Moves an item to another section")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item to be moved"),
+                        )
+                        .arg(
+                            Arg::with_name("section")
+                                .long("section")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides section to move item to"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("show")
+                        .about("Prints the library's items by section")
+                        .arg(
+                            Arg::with_name("section")
+                                .long("section")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides section to show, otherwise shows them all"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("search")
+                        .about("Finds items in library by name, allowing for typos")
+                        .arg(
+                            Arg::with_name("query")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name to search for"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("merge")
+                        .about("Merges items that are the same thing into one, or suggests some to merge")
+                        .arg(
+                            Arg::with_name("keep")
+                                .requires("dupes")
+                                .takes_value(true)
+                                .help("Provides name of item to keep"),
+                        )
+                        .arg(
+                            Arg::with_name("dupes")
+                                .requires("keep")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides names of items to merge into it, each in quotes if it has spaces"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("aliases")
+                        .about("Shows or changes the other names recipes call items by")
+                        .arg(
+                            Arg::with_name("item")
+                                .long("item")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item to show the aliases of, otherwise every item's"),
+                        )
+                        .subcommand(
+                            Command::new("add")
+                                .about("Lets recipes and searches find an item by another name")
+                                .arg(
+                                    Arg::with_name("item")
+                                        .long("item")
+                                        .required(true)
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .help("Provides name of item"),
+                                )
+                                .arg(
+                                    Arg::with_name("alias")
+                                        .long("alias")
+                                        .required(true)
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .help("Provides other name for it, e.g. \"green onions\" for scallions"),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("remove")
+                                .about("Removes one of an item's aliases")
+                                .arg(
+                                    Arg::with_name("item")
+                                        .long("item")
+                                        .required(true)
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .help("Provides name of item"),
+                                )
+                                .arg(
+                                    Arg::with_name("alias")
+                                        .long("alias")
+                                        .required(true)
+                                        .takes_value(true)
+                                        .multiple_values(true)
+                                        .help("Provides alias to remove"),
+                                ),
+                        ),
+                )
+                .subcommand(
+                    Command::new("check-first")
+                        .about("Puts an item on the list's checklist, to check for at home first")
+                        .arg(
+                            Arg::with_name("item")
+                                .long("item")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item"),
+                        )
+                        .arg(
+                            Arg::with_name("off")
+                                .long("off")
+                                .takes_value(false)
+                                .help("Puts the item straight on the list again"),
+                        ),
+                )
                 .arg(
                     Arg::with_name("path")
                         .long("path")
                         .takes_value(true)
-                        .default_value("groceries.json")
+                        .default_value(&config.library)
                         .help("Provides path for groceries library"),
+                )
+                .arg(
+                    Arg::with_name("list path")
+                        .long("list-path")
+                        .takes_value(true)
+                        .default_value(&config.list)
+                        .help("Provides path for shopping list"),
                 ),
         )
         .subcommand(
             Command::new("list")
                 .about("Makes shopping lists")
+                .subcommand_precedence_over_arg(true)
+                .subcommand(Command::new("show").about("Prints the saved list"))
+                .subcommand(
+                    Command::new("add-recipe")
+                        .about("Adds a recipe and the ingredients we need for it to the saved list")
+                        .arg(
+                            Arg::with_name("recipe")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of recipe"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("add-item")
+                        .about("Adds an item from the library to the saved list")
+                        .arg(
+                            Arg::with_name("item")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("remove")
+                        .about("Takes a recipe or item off the saved list")
+                        .arg(
+                            Arg::with_name("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of recipe or item"),
+                        ),
+                )
+                .subcommand(Command::new("clear").about("Takes everything off the saved list"))
+                .subcommand(Command::new("new").about("Starts a new, empty list in place of the saved one"))
+                .subcommand(
+                    Command::new("checklist")
+                        .about("Goes through the checklist, putting what we need on the list"),
+                )
+                .subcommand(
+                    Command::new("check")
+                        .about("Checks off an item we've bought and puts it in the pantry")
+                        .arg(
+                            Arg::with_name("item")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item bought"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("shop")
+                        .about("Goes through the list in the store, checking off what we get as we go"),
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .takes_value(true)
+                        .default_value(&config.list)
+                        .help("Provides path for shopping list"),
+                )
+                .arg(
+                    Arg::with_name("library path")
+                        .long("lib-path")
+                        .takes_value(true)
+                        .default_value(&config.library)
+                        .help("Provides path for groceries library"),
+                )
+                .arg(
+                    Arg::with_name("servings")
+                        .long("servings")
+                        .takes_value(true)
+                        .value_parser(clap::value_parser!(u32).range(1..))
+                        .help("Provides number of servings to shop for instead of asking for each recipe"),
+                )
+                .arg(
+                    Arg::with_name("grocery store")
+                        .long("store")
+                        .takes_value(true)
+                        .multiple_values(true)
+                        .help("Provides name of store to shop at, listing groceries in its walking order"),
+                ),
+        )
+        .subcommand(
+            Command::new("sections")
+                .about("Manages the library's sections, in the order we walk the store")
+                .subcommand(
+                    Command::new("add")
+                        .about("Adds a section")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of section to be added"),
+                        )
+                        .arg(
+                            Arg::with_name("at")
+                                .long("at")
+                                .takes_value(true)
+                                .value_parser(clap::value_parser!(usize))
+                                .help("Provides position to insert section at, otherwise adds it at the end"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("remove")
+                        .about("Removes a section")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of section to be removed"),
+                        )
+                        .arg(
+                            Arg::with_name("move to")
+                                .long("move-to")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides section to move the removed section's items to"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("rename")
+                        .about("Renames a section and moves its items along with it")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of section to be renamed"),
+                        )
+                        .arg(
+                            Arg::with_name("new")
+                                .long("new")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides new name of section"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("reorder")
+                        .about("Moves sections to the front, in the order given")
+                        .arg(
+                            Arg::with_name("order")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides sections in their new order"),
+                        ),
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .takes_value(true)
+                        .default_value(&config.library)
+                        .help("Provides path for groceries library"),
+                ),
+        )
+        .subcommand(
+            Command::new("pantry")
+                .about("Keeps track of what we have at home")
+                .subcommand(
+                    Command::new("set")
+                        .about("Sets how much of an item we have")
+                        .arg(
+                            Arg::with_name("item")
+                                .long("item")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item"),
+                        )
+                        .arg(
+                            Arg::with_name("amount")
+                                .long("amount")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides how much we have, e.g. \"2 cans\", otherwise just that we have some"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("use")
+                        .about("Takes an amount of an item out of the pantry")
+                        .arg(
+                            Arg::with_name("item")
+                                .long("item")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item"),
+                        )
+                        .arg(
+                            Arg::with_name("amount")
+                                .long("amount")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides how much we used, otherwise all of it"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("restock")
+                        .about("Adds an amount of an item to the pantry")
+                        .arg(
+                            Arg::with_name("item")
+                                .long("item")
+                                .required(false)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of item"),
+                        )
+                        .arg(
+                            Arg::with_name("amount")
+                                .long("amount")
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides how much we bought, otherwise just that we have some"),
+                        )
+                        .arg(
+                            Arg::with_name("from list")
+                                .long("from-list")
+                                .takes_value(false)
+                                .conflicts_with_all(&["item", "amount"])
+                                .required_unless_present("item")
+                                .help("Checks off everything on the saved shopping list and puts it in the pantry"),
+                        ),
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .takes_value(true)
+                        .default_value(&config.library)
+                        .help("Provides path for groceries library"),
+                )
+                .arg(
+                    Arg::with_name("list path")
+                        .long("list-path")
+                        .takes_value(true)
+                        .default_value(&config.list)
+                        .help("Provides path for shopping list"),
+                ),
+        )
+        .subcommand(
+            Command::new("stores")
+                .about("Manages the stores we shop at, their aisles and what they carry")
+                .subcommand(
+                    Command::new("add")
+                        .about("Adds a store")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of store to be added"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("remove")
+                        .about("Removes a store")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of store to be removed"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("aisles")
+                        .about("Sets the order we walk past sections and items at a store")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of store"),
+                        )
+                        .arg(
+                            Arg::with_name("order")
+                                .long("order")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides sections and items in walking order"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("carries")
+                        .about("Sets the sections and items a store stocks")
+                        .arg(
+                            Arg::with_name("name")
+                                .long("name")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .help("Provides name of store"),
+                        )
+                        .arg(
+                            Arg::with_name("stock")
+                                .long("stock")
+                                .required(true)
+                                .takes_value(true)
+                                .multiple_values(true)
+                                .min_values(0)
+                                .help("Provides sections and items the store carries, none for everything"),
+                        ),
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .takes_value(true)
+                        .default_value(&config.library)
+                        .help("Provides path for groceries library"),
+                ),
+        )
+        .subcommand(
+            Command::new("restore")
+                .about("Restores a backup of the library or shopping list")
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .takes_value(true)
+                        .default_value(&config.library)
+                        .help("Provides path for file to restore"),
+                )
+                .arg(
+                    Arg::with_name("backup")
+                        .long("backup")
+                        .takes_value(true)
+                        .value_parser(clap::value_parser!(usize))
+                        .help("Provides number of backup to restore, 1 being the most recent"),
+                ),
+        )
+        .subcommand(
+            Command::new("doctor")
+                .about("Checks the library for anything that doesn't add up")
+                .arg(
+                    Arg::with_name("fix")
+                        .long("fix")
+                        .takes_value(false)
+                        .help("Repairs what it finds, showing the changes before saving them"),
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .takes_value(true)
+                        .default_value(&config.library)
+                        .help("Provides path for groceries library"),
+                )
+                .arg(
+                    Arg::with_name("list path")
+                        .long("list-path")
+                        .takes_value(true)
+                        .default_value(&config.list)
+                        .help("Provides path for shopping list to keep up with repairs"),
+                ),
+        )
+        .subcommand(
+            Command::new("migrate")
+                .about("Upgrades the library and shopping list to the current file format")
+                .arg(
+                    Arg::with_name("check")
+                        .long("check")
+                        .takes_value(false)
+                        .help("Reports what would change without saving anything"),
+                )
                 .arg(
                     Arg::with_name("path")
                         .long("path")
                         .takes_value(true)
-                        .default_value("list.json")
+                        .default_value(&config.list)
                         .help("Provides path for shopping list"),
                 )
                 .arg(
                     Arg::with_name("library path")
                         .long("lib-path")
                         .takes_value(true)
-                        .default_value("groceries.json")
+                        .default_value(&config.library)
                         .help("Provides path for groceries library"),
                 ),
         )
@@ -0,0 +1,205 @@
+use crate::ReadError;
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// User settings read from `config.toml`.
+///
+/// Relative paths are relative to the data directory, see [`Locations`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub library: String,
+    pub list: String,
+    pub database: String,
+    pub store: String,
+    pub backups: usize,
+    /// Sections a new library starts with, see `grusterylist sections`
+    pub sections: Vec<String>,
+    pub prompts: Prompts,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            library: "groceries.json".to_string(),
+            list: "list.json".to_string(),
+            database: "grusterylist.db".to_string(),
+            store: "json".to_string(),
+            backups: crate::DEFAULT_BACKUPS,
+            sections: ["fresh", "pantry", "protein", "dairy", "freezer"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            prompts: Prompts::default(),
+        }
+    }
+}
+
+/// Which optional questions to ask.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Prompts {
+    /// Offer to show the library before adding groceries to it
+    pub view_library: bool,
+    /// Offer to print a saved shopping list before changing it
+    pub view_list: bool,
+    /// Ask before saving a shopping list, otherwise save it straight away
+    pub confirm_save: bool,
+}
+
+impl Default for Prompts {
+    fn default() -> Self {
+        Prompts {
+            view_library: true,
+            view_list: true,
+            confirm_save: true,
+        }
+    }
+}
+
+/// Where the config file and the data live.
+///
+/// `GRUSTERYLIST_HOME` holds both when it is set. Otherwise the config file is
+/// `$XDG_CONFIG_HOME/grusterylist/config.toml` and the data is kept in
+/// `$XDG_DATA_HOME/grusterylist`, with the usual XDG fallbacks under `$HOME`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Locations {
+    pub config_file: PathBuf,
+    pub data_dir: PathBuf,
+}
+
+impl Locations {
+    pub fn from_env() -> Self {
+        Locations::from_vars(|key| env::var_os(key))
+    }
+
+    fn from_vars<F: Fn(&str) -> Option<OsString>>(var: F) -> Self {
+        let var = |key: &str| {
+            var(key)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from)
+        };
+
+        if let Some(home) = var("GRUSTERYLIST_HOME") {
+            return Locations {
+                config_file: home.join("config.toml"),
+                data_dir: home,
+            };
+        }
+        let home = var("HOME").unwrap_or_else(|| PathBuf::from("."));
+        let config_home = var("XDG_CONFIG_HOME").unwrap_or_else(|| home.join(".config"));
+        let data_home = var("XDG_DATA_HOME").unwrap_or_else(|| home.join(".local").join("share"));
+        Locations {
+            config_file: config_home.join("grusterylist").join("config.toml"),
+            data_dir: data_home.join("grusterylist"),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Result<Config, ReadError> {
+        Config::load_from(&Locations::from_env())
+    }
+
+    /// Reads the config file, if there is one, and resolves its paths
+    /// against the data directory.
+    pub fn load_from(locations: &Locations) -> Result<Config, ReadError> {
+        let mut config: Config = if locations.config_file.exists() {
+            toml::from_str(&fs::read_to_string(&locations.config_file)?)?
+        } else {
+            Config::default()
+        };
+        for path in [&mut config.library, &mut config.list, &mut config.database] {
+            *path = resolve(&locations.data_dir, path);
+        }
+        Ok(config)
+    }
+}
+
+fn resolve(dir: &Path, path: &str) -> String {
+    dir.join(path).to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use assert_fs::prelude::*;
+
+    #[test]
+    fn test_locations() {
+        let vars = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| {
+                vars.iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| OsString::from(v))
+            }
+        };
+
+        let locations = Locations::from_vars(vars(&[("HOME", "/home/me")]));
+        assert_eq!(
+            locations.config_file,
+            Path::new("/home/me/.config/grusterylist/config.toml")
+        );
+        assert_eq!(
+            locations.data_dir,
+            Path::new("/home/me/.local/share/grusterylist")
+        );
+
+        let locations = Locations::from_vars(vars(&[
+            ("HOME", "/home/me"),
+            ("XDG_CONFIG_HOME", "/xdg/config"),
+            ("XDG_DATA_HOME", "/xdg/data"),
+        ]));
+        assert_eq!(
+            locations.config_file,
+            Path::new("/xdg/config/grusterylist/config.toml")
+        );
+        assert_eq!(locations.data_dir, Path::new("/xdg/data/grusterylist"));
+
+        let locations = Locations::from_vars(vars(&[
+            ("XDG_CONFIG_HOME", "/xdg/config"),
+            ("GRUSTERYLIST_HOME", "/groceries"),
+        ]));
+        assert_eq!(locations.config_file, Path::new("/groceries/config.toml"));
+        assert_eq!(locations.data_dir, Path::new("/groceries"));
+    }
+
+    #[test]
+    fn test_load_config() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = assert_fs::TempDir::new()?;
+        let locations = Locations {
+            config_file: dir.child("config.toml").path().to_path_buf(),
+            data_dir: dir.child("data").path().to_path_buf(),
+        };
+
+        let config = Config::load_from(&locations)?;
+        assert_eq!(
+            config.library,
+            resolve(&locations.data_dir, "groceries.json")
+        );
+        assert_eq!(config.prompts, Prompts::default());
+
+        dir.child("config.toml").write_str(
+            r#"
+            library = "/households/ours/groceries.json"
+            sections = ["fresh", "dairy"]
+
+            [prompts]
+            confirm_save = false
+            "#,
+        )?;
+        let config = Config::load_from(&locations)?;
+        assert_eq!(config.library, "/households/ours/groceries.json");
+        assert_eq!(config.list, resolve(&locations.data_dir, "list.json"));
+        assert_eq!(config.sections, vec!["fresh", "dairy"]);
+        assert!(config.prompts.view_list);
+        assert!(!config.prompts.confirm_save);
+        Ok(())
+    }
+}
@@ -0,0 +1,562 @@
+use crate::{
+    new_id, Groceries, GroceriesItemName, ItemId, RecipeId, RecipeName, ShoppingList, StoreEntry,
+};
+use std::fmt;
+
+/// How many unchanged lines [`diff`] shows around each change.
+const CONTEXT: usize = 2;
+
+/// Something in a library that doesn't add up, and where it is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    /// e.g. `collection[3] "olive oil"`
+    pub location: String,
+    pub description: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.description)
+    }
+}
+
+/// A change [`Groceries::repair`] made to an item's id, which the
+/// shopping list has to follow too.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Repaired {
+    /// The item called `name` had the id `old` of an item before it
+    NewId {
+        old: ItemId,
+        name: GroceriesItemName,
+        new: ItemId,
+    },
+    /// Item `from` was merged into item `into`
+    Merged { from: ItemId, into: ItemId },
+}
+
+impl Groceries {
+    /// Everything in the library that doesn't add up, in the order it comes
+    /// in the file.
+    pub fn diagnose(&self) -> Vec<Problem> {
+        let mut problems = vec![];
+        let mut problem = |location: &str, description: String| {
+            problems.push(Problem {
+                location: location.to_string(),
+                description,
+            })
+        };
+
+        for (i, section) in self.sections.iter().enumerate() {
+            if let Some(j) = self.sections[..i].iter().position(|x| x == section) {
+                let here = format!("sections[{}] \"{}\"", i, section);
+                problem(&here, format!("is listed already, as sections[{}]", j));
+            }
+        }
+
+        for (i, item) in self.collection.iter().enumerate() {
+            let here = format!("collection[{}] \"{}\"", i, item.name);
+            if let Some(j) = self.collection[..i].iter().position(|x| x.id == item.id) {
+                problem(
+                    &here,
+                    format!("has the id `{}` of collection[{}]", item.id, j),
+                );
+            }
+            if let Some(j) = self.collection[..i]
+                .iter()
+                .position(|x| same_name(&x.name.0, &item.name.0))
+            {
+                let other = &self.collection[j];
+                problem(
+                    &here,
+                    format!("has the name of collection[{}] \"{}\"", j, other.name),
+                );
+            }
+            if !self.sections.contains(&item.section) {
+                problem(
+                    &here,
+                    format!(
+                        "is in section \"{}\", which isn't in `sections`",
+                        item.section
+                    ),
+                );
+            }
+            for (k, alias) in item.aliases.iter().enumerate() {
+                let description = match self.alias_taken(i, k) {
+                    None => continue,
+                    Some(j) if j == i => "repeats the item's name or another alias".to_string(),
+                    Some(j) => format!(
+                        "is what collection[{}] \"{}\" is called already",
+                        j, self.collection[j].name
+                    ),
+                };
+                problem(
+                    &format!("{} aliases[{}] \"{}\"", here, k, alias),
+                    description,
+                );
+            }
+        }
+
+        for (i, recipe) in self.recipes.iter().enumerate() {
+            let here = format!("recipes[{}] \"{}\"", i, recipe.name);
+            if let Some(j) = self.recipes[..i].iter().position(|x| x.id == recipe.id) {
+                problem(
+                    &here,
+                    format!("has the id `{}` of recipes[{}]", recipe.id, j),
+                );
+            }
+            if let Some(j) = self.recipes[..i]
+                .iter()
+                .position(|x| same_name(&x.name.0, &recipe.name.0))
+            {
+                let other = &self.recipes[j];
+                problem(
+                    &here,
+                    format!("has the name of recipes[{}] \"{}\"", j, other.name),
+                );
+            }
+            for (k, ingredient) in recipe.ingredients.iter().enumerate() {
+                let here = format!("{} ingredients[{}]", here, k);
+                if self.item_by_id(&ingredient.item).is_none() {
+                    problem(
+                        &here,
+                        format!("is item `{}`, which isn't in `collection`", ingredient.item),
+                    );
+                } else if let Some(j) = recipe.ingredients[..k]
+                    .iter()
+                    .position(|x| x.item == ingredient.item)
+                {
+                    problem(
+                        &here,
+                        format!(
+                            "is item `{}` again, after ingredients[{}]",
+                            ingredient.item, j
+                        ),
+                    );
+                }
+            }
+        }
+
+        for (i, store) in self.stores.iter().enumerate() {
+            for (field, entries) in [("aisles", &store.aisles), ("carries", &store.carries)] {
+                for (k, entry) in entries.iter().enumerate() {
+                    let here = format!("stores[{}] \"{}\" {}[{}]", i, store, field, k);
+                    match entry {
+                        _ if self.has_entry(entry) => continue,
+                        StoreEntry::Section(section) => problem(
+                            &here,
+                            format!("is section \"{}\", which isn't in `sections`", section),
+                        ),
+                        StoreEntry::Item(id) => problem(
+                            &here,
+                            format!("is item `{}`, which isn't in `collection`", id),
+                        ),
+                    }
+                }
+            }
+        }
+
+        problems
+    }
+
+    /// Fixes everything [`Groceries::diagnose`] finds, losing as little as it
+    /// can: items whose names differ only in case are merged into the first,
+    /// recipes with the same name are told apart by number, and references to
+    /// what isn't there are dropped. Returns what it did to item ids, in order.
+    pub fn repair(&mut self) -> Vec<Repaired> {
+        let mut repaired = vec![];
+        let mut sections = vec![];
+        for section in self.sections.drain(..) {
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+        self.sections = sections;
+        self.fill_sections(&[]);
+
+        // recipes keep using the first item with an id
+        for i in 0..self.collection.len() {
+            if self.collection[..i]
+                .iter()
+                .any(|x| x.id == self.collection[i].id)
+            {
+                let id = new_id(&self.collection[i].name.0, |id| {
+                    self.collection.iter().any(|x| x.id.0 == id)
+                });
+                let item = &mut self.collection[i];
+                repaired.push(Repaired::NewId {
+                    old: std::mem::replace(&mut item.id, ItemId(id)),
+                    name: item.name.clone(),
+                    new: item.id.clone(),
+                });
+            }
+        }
+        let mut i = 0;
+        while i < self.collection.len() {
+            match self.collection[..i]
+                .iter()
+                .position(|x| same_name(&x.name.0, &self.collection[i].name.0))
+            {
+                Some(j) => {
+                    repaired.push(Repaired::Merged {
+                        from: self.collection[i].id.clone(),
+                        into: self.collection[j].id.clone(),
+                    });
+                    self.merge_item(i, j)
+                }
+                None => i += 1,
+            }
+        }
+        for i in 0..self.collection.len() {
+            let mut k = 0;
+            while k < self.collection[i].aliases.len() {
+                if self.alias_taken(i, k).is_some() {
+                    self.collection[i].aliases.remove(k);
+                } else {
+                    k += 1;
+                }
+            }
+        }
+
+        for i in 0..self.recipes.len() {
+            if self.recipes[..i].iter().any(|x| x.id == self.recipes[i].id) {
+                let id = new_id(&self.recipes[i].name.0, |id| {
+                    self.recipes.iter().any(|x| x.id.0 == id)
+                });
+                self.recipes[i].id = RecipeId(id);
+            }
+            let name = self.recipes[i].name.0.clone();
+            let taken = |recipes: &[crate::RecipeRecord], name: &str| {
+                recipes.iter().any(|x| same_name(&x.name.0, name))
+            };
+            if taken(&self.recipes[..i], &name) {
+                let mut n = 2;
+                while taken(&self.recipes, &format!("{} ({})", name, n)) {
+                    n += 1;
+                }
+                self.recipes[i].name = RecipeName(format!("{} ({})", name, n));
+            }
+        }
+        let items: Vec<ItemId> = self.collection.iter().map(|item| item.id.clone()).collect();
+        for recipe in self.recipes.iter_mut() {
+            let mut seen = vec![];
+            recipe.ingredients.retain(|ingredient| {
+                let keep = items.contains(&ingredient.item) && !seen.contains(&ingredient.item);
+                seen.push(ingredient.item.clone());
+                keep
+            });
+        }
+
+        let unknown: Vec<StoreEntry> = self
+            .stores
+            .iter()
+            .flat_map(|store| store.aisles.iter().chain(store.carries.iter()))
+            .filter(|entry| !self.has_entry(entry))
+            .cloned()
+            .collect();
+        for store in self.stores.iter_mut() {
+            for entry in unknown.iter() {
+                store.forget(entry);
+            }
+        }
+        repaired
+    }
+
+    // the item that alias `k` of item `i` already names, which may be item `i`
+    // itself, going by other items' names and the aliases of items before it
+    fn alias_taken(&self, i: usize, k: usize) -> Option<usize> {
+        let item = &self.collection[i];
+        let alias = &item.aliases[k];
+        if same_name(&item.name.0, alias) || item.aliases[..k].iter().any(|x| same_name(x, alias)) {
+            return Some(i);
+        }
+        self.collection.iter().enumerate().position(|(j, x)| {
+            j != i && (same_name(&x.name.0, alias) || (j < i && x.has_alias(alias)))
+        })
+    }
+
+    fn has_entry(&self, entry: &StoreEntry) -> bool {
+        match entry {
+            StoreEntry::Section(section) => self.sections.contains(section),
+            StoreEntry::Item(id) => self.item_by_id(id).is_some(),
+        }
+    }
+}
+
+impl ShoppingList {
+    /// Follows what [`Groceries::repair`] did to item ids, so the list's
+    /// items are still the ones they were in the repaired `groceries`.
+    pub fn repair(&mut self, repaired: &[Repaired], groceries: &Groceries) {
+        for change in repaired {
+            match change {
+                // the copies named like the item that was given a new id
+                Repaired::NewId { old, name, new } => {
+                    for item in self
+                        .groceries
+                        .iter_mut()
+                        .chain(self.checklist.iter_mut())
+                        .filter(|item| &item.id == old && &item.name == name)
+                    {
+                        item.id = new.clone();
+                    }
+                    // having bought it goes with the only copy on the list
+                    if !self.groceries.iter().any(|item| &item.id == old)
+                        && self.groceries.iter().any(|item| &item.id == new)
+                        && self.purchased.remove(old)
+                    {
+                        self.purchased.insert(new.clone());
+                    }
+                }
+                Repaired::Merged { from, into } => {
+                    if let Some(keep) = groceries.item_by_id(into) {
+                        self.merge_items(keep, std::slice::from_ref(from));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+/// The lines that differ between `old` and `new`, marked `-` for taken out
+/// and `+` for put in, with a few unchanged lines around each change. Changes
+/// far apart are separated by `...`.
+pub fn diff(old: &str, new: &str) -> Vec<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (a, b) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+
+    // common[i][j] is how many lines a[i..] and b[j..] have in common
+    let mut common = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            common[i][j] = if a[i] == b[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let mut lines: Vec<(char, &str)> = old[..prefix].iter().map(|line| (' ', *line)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push((' ', a[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < a.len() && (j == b.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(('-', a[i]));
+            i += 1;
+        } else {
+            lines.push(('+', b[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[old.len() - suffix..].iter().map(|line| (' ', *line)));
+
+    let near_change = |k: usize| {
+        lines[k.saturating_sub(CONTEXT)..(k + CONTEXT + 1).min(lines.len())]
+            .iter()
+            .any(|(mark, _)| *mark != ' ')
+    };
+    let mut shown = vec![];
+    let mut skipped = false;
+    for (k, (mark, line)) in lines.iter().enumerate() {
+        if !near_change(k) {
+            skipped = true;
+            continue;
+        }
+        if skipped && !shown.is_empty() {
+            shown.push("...".to_string());
+        }
+        skipped = false;
+        shown.push(format!("{} {}", mark, line));
+    }
+    shown
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diagnose_and_repair() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::from_document(serde_json::from_str(
+            r#"{"schema_version":4,"sections":["fresh","pantry","fresh"],
+            "collection":[
+                {"id":"olive-oil","name":"olive oil","section":"pantry"},
+                {"id":"olive-oil-2","name":"Olive Oil","section":"pantry","check_first":true},
+                {"id":"garlic","name":"garlic","section":"fresh","aliases":["cloves","Garlic","chickpeas"]},
+                {"id":"garlic","name":"chickpeas","section":"dry goods"}
+            ],
+            "recipes":[
+                {"id":"hummus","name":"hummus","ingredients":[
+                    {"item":"tahini"},{"item":"garlic"},{"item":"olive-oil-2"},{"item":"garlic"}
+                ]},
+                {"id":"hummus","name":"Hummus","ingredients":[
+                    {"item":"olive-oil"},{"item":"olive-oil-2"}
+                ]}
+            ],
+            "stores":[{"name":"corner shop","aisles":[{"item":"olive-oil-2"},{"section":"bakery"},{"item":"tahini"}]}]}"#,
+        )?)?;
+        let problems: Vec<String> = g.diagnose().iter().map(|p| p.to_string()).collect();
+        insta::assert_json_snapshot!(problems, @r###"
+        [
+          "sections[2] \"fresh\": is listed already, as sections[0]",
+          "collection[1] \"Olive Oil\": has the name of collection[0] \"olive oil\"",
+          "collection[2] \"garlic\" aliases[1] \"Garlic\": repeats the item's name or another alias",
+          "collection[2] \"garlic\" aliases[2] \"chickpeas\": is what collection[3] \"chickpeas\" is called already",
+          "collection[3] \"chickpeas\": has the id `garlic` of collection[2]",
+          "collection[3] \"chickpeas\": is in section \"dry goods\", which isn't in `sections`",
+          "recipes[0] \"hummus\" ingredients[0]: is item `tahini`, which isn't in `collection`",
+          "recipes[0] \"hummus\" ingredients[3]: is item `garlic` again, after ingredients[1]",
+          "recipes[1] \"Hummus\": has the id `hummus` of recipes[0]",
+          "recipes[1] \"Hummus\": has the name of recipes[0] \"hummus\"",
+          "stores[0] \"corner shop\" aisles[1]: is section \"bakery\", which isn't in `sections`",
+          "stores[0] \"corner shop\" aisles[2]: is item `tahini`, which isn't in `collection`"
+        ]
+        "###);
+
+        let mut sl = ShoppingList::from_document(
+            serde_json::from_str(
+                r#"{"schema_version":3,"recipes":["hummus"],
+                "checklist":[{"id":"garlic","name":"chickpeas","section":"dry goods"}],
+                "groceries":[{"id":"olive-oil-2","name":"Olive Oil","section":"pantry"}],
+                "purchased":["olive-oil-2"]}"#,
+            )?,
+            None,
+        )?;
+
+        let repaired = g.repair();
+        assert_eq!(g.diagnose(), vec![]);
+        insta::assert_json_snapshot!(g, @r###"
+        {
+          "schema_version": 4,
+          "sections": [
+            "fresh",
+            "pantry",
+            "dry goods"
+          ],
+          "collection": [
+            {
+              "id": "olive-oil",
+              "name": "olive oil",
+              "section": "pantry",
+              "check_first": true
+            },
+            {
+              "id": "garlic",
+              "name": "garlic",
+              "section": "fresh",
+              "aliases": [
+                "cloves"
+              ]
+            },
+            {
+              "id": "chickpeas",
+              "name": "chickpeas",
+              "section": "dry goods"
+            }
+          ],
+          "recipes": [
+            {
+              "id": "hummus",
+              "name": "hummus",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "olive-oil"
+                }
+              ]
+            },
+            {
+              "id": "hummus-2",
+              "name": "Hummus (2)",
+              "ingredients": [
+                {
+                  "item": "olive-oil"
+                }
+              ]
+            }
+          ],
+          "stores": [
+            {
+              "name": "corner shop",
+              "aisles": [
+                {
+                  "item": "olive-oil"
+                }
+              ]
+            }
+          ]
+        }
+        "###);
+
+        // the list follows the items that were merged or given new ids
+        sl.repair(&repaired, &g);
+        insta::assert_json_snapshot!(sl, @r###"
+        {
+          "schema_version": 3,
+          "checklist": [
+            {
+              "id": "chickpeas",
+              "name": "chickpeas",
+              "section": "dry goods"
+            }
+          ],
+          "recipes": [
+            "hummus"
+          ],
+          "groceries": [
+            {
+              "id": "olive-oil",
+              "name": "olive oil",
+              "section": "pantry",
+              "check_first": true
+            }
+          ],
+          "purchased": [
+            "olive-oil"
+          ]
+        }
+        "###);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk";
+        insta::assert_json_snapshot!(diff(old, new), @r###"
+        [
+          "  a",
+          "- b",
+          "+ B",
+          "  c",
+          "  d",
+          "...",
+          "  i",
+          "  j",
+          "+ k"
+        ]
+        "###);
+        assert!(diff(old, old).is_empty());
+    }
+}
@@ -1,7 +1,30 @@
 custom_error::custom_error! {pub ReadError
-    DeserializingError{ source: serde_json::Error } = "Invalid JSON file",
+    DeserializingError{ source: serde_json::Error } = @{ format!("Invalid JSON file: {}", source) },
+    SchemaVersionError{ version: u64 } = "File has schema version {version}, which is newer than this grusterylist supports",
     ParseInputError = "Invalid input",
+    ConfigError{ source: toml::de::Error } = @{ format!("Invalid config file: {}", source) },
     ReadWriteError{ source: std::io::Error } = "Error reading/writing file",
+    DatabaseError{ source: rusqlite::Error } = "Error reading/writing database",
     ItemNotFound = "Item not found",
+    ItemExists = "Item already in library",
+    RecipeNotFound = "Recipe not found",
+    RecipeExists = "Recipe already in library",
+    IngredientNotFound = "Ingredient not found in recipe",
+    IngredientsNotInLibrary{ items: String } = "Ingredients not in the library: {items}",
+    AliasNotFound = "Item has no such alias",
+    AliasNotName{ alias: String, item: String } = "'{alias}' is another name for '{item}', use its name",
+    StepNotFound = "No such step in recipe instructions",
+    NotInPantry = "Item isn't in the pantry",
+    UnitMismatch = "Amount isn't in a unit the pantry's stock converts to",
+    SectionNotFound = "Section not found",
+    SectionExists = "Section already exists",
+    SectionNotEmpty = "Section still has items in it, move them with --move-to",
+    StoreNotFound = "Store not found",
+    StoreExists = "Store already exists",
+    NotSectionOrItem = "Not a section or item in the library",
     LibraryNotFound = "No groceries library found.\nRun grusterylist groceries to create a groceries library",
+    ListNotFound = "No saved shopping list found",
+    NotOnList = "Not on the shopping list",
+    DidYouMean{ error: String, suggestions: String } = "{error}, did you mean {suggestions}?",
+    BackupNotFound = "Backup not found",
 }
@@ -1,250 +1,2183 @@
-use crate::{ReadError, read};
-use crate::{GroceriesItem, GroceriesItemName, GroceriesItemSection, Ingredients, Recipe};
+use crate::{migrate_groceries, read, write, ReadError, GROCERIES_SCHEMA_VERSION};
+use crate::{new_id, GroceriesItem, GroceriesItemName, GroceriesItemSection, ItemId};
+use crate::{Amount, Ingredient, Ingredients};
+use crate::{GroceryStore, StoreEntry};
+use crate::{RecipeId, RecipeIngredient, RecipeName, RecipeRecord};
 use serde::{Deserialize, Serialize};
-use std::{fs, path::Path};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
 
-#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Groceries {
+    pub schema_version: u64,
     pub sections: Vec<GroceriesItemSection>,
     pub collection: Vec<GroceriesItem>,
-    pub recipes: Vec<Recipe>,
+    pub recipes: Vec<RecipeRecord>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stores: Vec<GroceryStore>,
 }
 
-impl Groceries {
-    pub fn new_initialized() -> Result<Self, ReadError> {
-        Ok(Groceries {
+impl Default for Groceries {
+    fn default() -> Self {
+        Groceries {
+            schema_version: GROCERIES_SCHEMA_VERSION,
             sections: vec![],
             collection: vec![],
             recipes: vec![],
-        })
+            stores: vec![],
+        }
+    }
+}
+
+impl Groceries {
+    pub fn new_initialized() -> Result<Self, ReadError> {
+        Ok(Groceries::default())
     }
 
+    /// Items whose names or aliases are like `name`, best match first.
     pub fn get_item_matches(&self, name: &str) -> impl Iterator<Item = &GroceriesItem> {
-        self.collection.iter().filter(|item| item.matches(name)).collect::<Vec<_>>().into_iter()
+        crate::rank(&self.collection, |item| item.similarity(name)).into_iter()
+    }
+
+    /// Recipes whose names are like `name`, best match first.
+    pub fn get_recipe_matches(&self, name: &str) -> impl Iterator<Item = &RecipeRecord> {
+        crate::search(name, &self.recipes, |recipe| &recipe.name.0).into_iter()
     }
 
     pub fn from_path<P: AsRef<Path> + Copy>(path: P) -> Result<Groceries, ReadError> {
-        Ok(serde_json::from_reader(read(path)?)?)
+        Groceries::from_document(serde_json::from_reader(read(path)?)?)
     }
 
-    pub fn add_item(&mut self, item: GroceriesItem) {
+    /// Upgrades a library read in any earlier schema version and deserializes it.
+    pub fn from_document(mut document: serde_json::Value) -> Result<Groceries, ReadError> {
+        migrate_groceries(&mut document)?;
+        Ok(serde_json::from_value(document)?)
+    }
+
+    /// Adds `item`, with a new id if another item has its id already.
+    pub fn add_item(&mut self, mut item: GroceriesItem) {
+        if self.item_by_id(&item.id).is_some() {
+            item.id = ItemId(new_id(&item.name.0, |id| {
+                self.collection.iter().any(|x| x.id.0 == id)
+            }));
+        }
         self.collection.push(item);
     }
 
+    pub fn item(&self, name: &str) -> Result<&GroceriesItem, ReadError> {
+        Ok(&self.collection[self.item_position(name)?])
+    }
+
+    pub fn item_by_id(&self, id: &ItemId) -> Option<&GroceriesItem> {
+        self.collection.iter().find(|item| &item.id == id)
+    }
+
+    pub fn item_by_id_mut(&mut self, id: &ItemId) -> Option<&mut GroceriesItem> {
+        self.collection.iter_mut().find(|item| &item.id == id)
+    }
+
+    /// Deletes an item, taking it out of the recipes that use it.
     pub fn delete_item(&mut self, name: &str) -> Result<(), ReadError> {
-        if let Ok(i) = self
-            .collection
-            .iter()
-            .position(|x| x.name == GroceriesItemName(name.to_string()))
-            .ok_or(ReadError::ItemNotFound)
-        {
-            self.collection.remove(i);
+        let i = self.item_position(name)?;
+        let item = self.collection.remove(i);
+        for recipe in self.recipes.iter_mut() {
+            recipe
+                .ingredients
+                .retain(|ingredient| ingredient.item != item.id);
+        }
+        for store in self.stores.iter_mut() {
+            store.forget(&StoreEntry::Item(item.id.clone()));
+        }
+        Ok(())
+    }
+
+    /// Adds a new item to one of the library's sections.
+    pub fn add_item_to_section(&mut self, name: &str, section: &str) -> Result<(), ReadError> {
+        if self.item_position(name).is_ok() || self.alias_position(name).is_some() {
+            return Err(ReadError::ItemExists);
         }
+        let section = self.sections[self.section_position(section)?].clone();
+        self.add_item(GroceriesItem::new_initialized(
+            GroceriesItemName(name.to_string()),
+            section,
+        ));
+        Ok(())
+    }
+
+    /// Renames an item, which recipes and stores know by its id.
+    pub fn rename_item(&mut self, name: &str, new_name: &str) -> Result<(), ReadError> {
+        let i = self.item_position(name)?;
+        // an item can take one of its own aliases as its name
+        let taken = self
+            .item_position(new_name)
+            .ok()
+            .or_else(|| self.alias_position(new_name));
+        if taken.is_some_and(|j| j != i) {
+            return Err(ReadError::ItemExists);
+        }
+        let item = &mut self.collection[i];
+        let swap = item.has_alias(new_name);
+        item.aliases
+            .retain(|alias| alias.to_lowercase() != new_name.trim().to_lowercase());
+        let name = std::mem::replace(&mut item.name, GroceriesItemName(new_name.to_string()));
+        if swap {
+            // and goes by its old name as well
+            item.aliases.push(name.0);
+        }
+        Ok(())
+    }
+
+    /// Merges the items named `dupes` into `keep` and returns their ids.
+    /// Recipes that used them use `keep` instead, stores that named them name
+    /// `keep`, and what we had of them at home is added to it.
+    pub fn merge_items(&mut self, keep: &str, dupes: &[&str]) -> Result<Vec<ItemId>, ReadError> {
+        // check everything exists before changing anything
+        self.item_position(keep)?;
+        let mut ids = vec![];
+        for dupe in dupes {
+            if *dupe == keep {
+                return Err(ReadError::ParseInputError);
+            }
+            ids.push(self.item(dupe)?.id.clone());
+        }
+        for dupe in dupes {
+            // a dupe named twice is merged already
+            if let Ok(from) = self.item_position(dupe) {
+                let into = self.item_position(keep)?;
+                self.merge_item(from, into);
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Folds the item at `from` into the one at `into`, which recipes and
+    /// stores use instead.
+    pub(crate) fn merge_item(&mut self, from: usize, into: usize) {
+        let item = self.collection.remove(from);
+        let into = &mut self.collection[if from < into { into - 1 } else { into }];
+        if let Some(on_hand) = &item.on_hand {
+            // amounts in units that don't add up leave what we had
+            let _ = into.restock(Some(on_hand));
+        }
+        into.check_first |= item.check_first;
+        // recipes that call it by the other item's names still find it
+        for alias in item.names() {
+            if alias.to_lowercase() != into.name.0.to_lowercase() && !into.has_alias(alias) {
+                into.aliases.push(alias.to_string());
+            }
+        }
+        let id = into.id.clone();
+        for recipe in self.recipes.iter_mut() {
+            recipe.replace_ingredient(&item.id, &id);
+        }
+        for store in self.stores.iter_mut() {
+            store.replace(
+                &StoreEntry::Item(item.id.clone()),
+                &StoreEntry::Item(id.clone()),
+            );
+        }
+    }
+
+    /// Groups of items whose names, or aliases, are so alike they may be the
+    /// same item twice, in library order. Different names for the same thing,
+    /// like "parmesan" and "parmigiano", aren't found unless an alias is alike.
+    pub fn duplicates(&self) -> Vec<Vec<&GroceriesItem>> {
+        let alike = |a: &GroceriesItem, b: &GroceriesItem| {
+            a.names()
+                .any(|name| b.similarity(name) >= crate::DUPLICATE_THRESHOLD)
+        };
+        // each item's group is named by its first item
+        let mut group: Vec<usize> = (0..self.collection.len()).collect();
+        for i in 0..self.collection.len() {
+            for j in 0..i {
+                if group[i] != group[j] && alike(&self.collection[i], &self.collection[j]) {
+                    let (first, other) = (group[i].min(group[j]), group[i].max(group[j]));
+                    group
+                        .iter_mut()
+                        .filter(|g| **g == other)
+                        .for_each(|g| *g = first);
+                }
+            }
+        }
+        (0..self.collection.len())
+            .map(|g| {
+                self.collection
+                    .iter()
+                    .zip(group.iter())
+                    .filter(|(_, x)| **x == g)
+                    .map(|(item, _)| item)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|items| items.len() > 1)
+            .collect()
+    }
+
+    /// Moves an item to another of the library's sections.
+    pub fn move_item(&mut self, name: &str, section: &str) -> Result<(), ReadError> {
+        let i = self.item_position(name)?;
+        self.collection[i].section = self.sections[self.section_position(section)?].clone();
         Ok(())
     }
 
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ReadError> {
         let s = serde_json::to_string(&self)?;
-        Ok(fs::write(path, s)?)
+        write(path, s)
     }
 
     pub fn items(&self) -> impl Iterator<Item = &GroceriesItem> {
         self.sections
             .iter()
-            .flat_map(|sec| self
-              .collection
-              .iter()
-              .filter(|x| x.section.0.contains(&sec.0)))
-              .collect::<Vec<_>>()
-              .into_iter()
+            .flat_map(|sec| self.collection.iter().filter(move |x| &x.section == sec))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Starts a library with no sections off with `defaults`, then adds any
+    /// section an item is in that the library doesn't list yet.
+    pub fn fill_sections(&mut self, defaults: &[String]) {
+        if self.sections.is_empty() {
+            self.sections = defaults
+                .iter()
+                .map(|s| GroceriesItemSection(s.clone()))
+                .collect();
+        }
+        for item in self.collection.iter() {
+            if !self.sections.contains(&item.section) {
+                self.sections.push(item.section.clone());
+            }
+        }
+    }
+
+    fn section_position(&self, name: &str) -> Result<usize, ReadError> {
+        self.sections
+            .iter()
+            .position(|x| x.0 == name)
+            .ok_or(ReadError::SectionNotFound)
     }
 
-    pub fn recipes(&self) -> impl Iterator<Item = &Recipe> {
+    /// Adds a section before section number `at`, counting from 1, or at the end.
+    pub fn add_section(&mut self, name: &str, at: Option<usize>) -> Result<(), ReadError> {
+        if self.section_position(name).is_ok() {
+            return Err(ReadError::SectionExists);
+        }
+        let section = GroceriesItemSection(name.to_string());
+        match at {
+            Some(at) if at == 0 || at > self.sections.len() + 1 => {
+                return Err(ReadError::ParseInputError)
+            }
+            Some(at) => self.sections.insert(at - 1, section),
+            None => self.sections.push(section),
+        }
+        Ok(())
+    }
+
+    /// Removes a section, first moving its items to `move_to` if given.
+    /// A section with items in it is only removed if they have somewhere to go.
+    pub fn remove_section(&mut self, name: &str, move_to: Option<&str>) -> Result<(), ReadError> {
+        let i = self.section_position(name)?;
+        let move_to = match move_to {
+            Some(other) if other == name => return Err(ReadError::ParseInputError),
+            Some(other) => Some(self.sections[self.section_position(other)?].clone()),
+            None => None,
+        };
+        for item in self
+            .collection
+            .iter_mut()
+            .filter(|item| item.section.0 == name)
+        {
+            match &move_to {
+                Some(section) => item.section = section.clone(),
+                None => return Err(ReadError::SectionNotEmpty),
+            }
+        }
+        let section = self.sections.remove(i);
+        for store in self.stores.iter_mut() {
+            store.forget(&StoreEntry::Section(section.clone()));
+        }
+        Ok(())
+    }
+
+    /// Renames a section along with the section of every item in it.
+    pub fn rename_section(&mut self, name: &str, new_name: &str) -> Result<(), ReadError> {
+        let i = self.section_position(name)?;
+        if self.section_position(new_name).is_ok() {
+            return Err(ReadError::SectionExists);
+        }
+        let new_section = GroceriesItemSection(new_name.to_string());
+        for item in self
+            .collection
+            .iter_mut()
+            .filter(|item| item.section.0 == name)
+        {
+            item.section = new_section.clone();
+        }
+        let section = std::mem::replace(&mut self.sections[i], new_section.clone());
+        for store in self.stores.iter_mut() {
+            store.replace(
+                &StoreEntry::Section(section.clone()),
+                &StoreEntry::Section(new_section.clone()),
+            );
+        }
+        Ok(())
+    }
+
+    /// Puts `order` first, in that order, followed by the other sections as they were.
+    pub fn reorder_sections(&mut self, order: &[&str]) -> Result<(), ReadError> {
+        let mut sections = vec![];
+        for name in order {
+            let section = self.sections[self.section_position(name)?].clone();
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+        for section in self.sections.iter() {
+            if !sections.contains(section) {
+                sections.push(section.clone());
+            }
+        }
+        self.sections = sections;
+        Ok(())
+    }
+
+    pub fn store(&self, name: &str) -> Result<&GroceryStore, ReadError> {
+        self.stores
+            .iter()
+            .find(|store| store.name == name)
+            .ok_or(ReadError::StoreNotFound)
+    }
+
+    fn store_mut(&mut self, name: &str) -> Result<&mut GroceryStore, ReadError> {
+        self.stores
+            .iter_mut()
+            .find(|store| store.name == name)
+            .ok_or(ReadError::StoreNotFound)
+    }
+
+    pub fn add_store(&mut self, name: &str) -> Result<(), ReadError> {
+        if self.store(name).is_ok() {
+            return Err(ReadError::StoreExists);
+        }
+        self.stores.push(GroceryStore::new(name));
+        Ok(())
+    }
+
+    pub fn remove_store(&mut self, name: &str) -> Result<(), ReadError> {
+        self.store(name)?;
+        self.stores.retain(|store| store.name != name);
+        Ok(())
+    }
+
+    // every name given has to be a section or an item in the library, a
+    // section first if it's both
+    fn sections_or_items(&self, names: &[&str]) -> Result<Vec<StoreEntry>, ReadError> {
+        names
+            .iter()
+            .map(|name| match self.section_position(name) {
+                Ok(i) => Ok(StoreEntry::Section(self.sections[i].clone())),
+                Err(_) => match self.lookup_item(name) {
+                    Ok(item) => Ok(StoreEntry::Item(item.id.clone())),
+                    Err(_) => Err(ReadError::NotSectionOrItem),
+                },
+            })
+            .collect()
+    }
+
+    /// Sets the order we walk past sections and items in at a store.
+    pub fn set_store_aisles(&mut self, store: &str, aisles: &[&str]) -> Result<(), ReadError> {
+        let aisles = self.sections_or_items(aisles)?;
+        self.store_mut(store)?.aisles = aisles;
+        Ok(())
+    }
+
+    /// Sets the sections and items a store stocks, none meaning everything.
+    pub fn set_store_carries(&mut self, store: &str, carries: &[&str]) -> Result<(), ReadError> {
+        let carries = self.sections_or_items(carries)?;
+        self.store_mut(store)?.carries = carries;
+        Ok(())
+    }
+
+    /// Guesses which of the library's sections `item` is in, from what's
+    /// already in them, and how sure the guess is.
+    pub fn guess_section(&self, item: &str) -> Option<(&GroceriesItemSection, f64)> {
+        crate::guess_section(item, &self.sections, &self.collection)
+    }
+
+    /// Works out which of the library's sections `item` is in, asking by
+    /// number or name unless there's a confident guess. A guess we aren't
+    /// sure of is taken by entering nothing.
+    pub fn prompt_for_section(&self, item: &str) -> Result<GroceriesItemSection, ReadError> {
+        if self.sections.is_empty() {
+            return Err(ReadError::SectionNotFound);
+        }
+        let guess = self.guess_section(item);
+        if let Some((section, confidence)) = guess {
+            if confidence >= crate::CONFIDENT_GUESS {
+                eprintln!("{} goes in {}", item, section);
+                return Ok(section.clone());
+            }
+        }
+        loop {
+            eprintln!("which section is {} in?", item);
+            for (i, section) in self.sections.iter().enumerate() {
+                eprintln!("*{}* {}", i + 1, section);
+            }
+            if let Some((section, _)) = guess {
+                eprintln!("*enter* for {}", section);
+            }
+            let input = crate::get_user_input()?;
+            let chosen = match input.parse::<usize>() {
+                Ok(n) if n >= 1 => self.sections.get(n - 1),
+                _ if input.is_empty() => guess.map(|(section, _)| section),
+                _ => self.sections.iter().find(|section| section.0 == input),
+            };
+            match chosen {
+                Some(section) => return Ok(section.clone()),
+                None if input.is_empty() => return Err(ReadError::ParseInputError),
+                None => eprintln!("re-enter section information"),
+            }
+        }
+    }
+
+    pub fn recipes(&self) -> impl Iterator<Item = &RecipeRecord> {
         self.recipes.iter()
     }
 
-    // check if ingredients already in lib or add them if not
-    pub fn check_recipe_ingredients(&mut self, ingredients: &str) -> Result<(), ReadError> {
-        let ingredients = Ingredients::from_input_string(ingredients)?;
-        // add new items to groceries
-        for ingredient in ingredients.iter() {
-            if self.collection.iter().all(|g| &g.name != ingredient) {
-                let mut section_input_ok = false;
-                let mut section_input = String::new();
-                while !section_input_ok {
-                    eprintln!(
-                        "which section is {} in?\n\
-                    *1* fresh
-                    *2* pantry 
-                    *3* protein 
-                    *4* dairy 
-                    *5* freezer",
-                        ingredient
-                    );
-
-                    let input = crate::get_user_input()?;
-
-                    section_input = match &input {
-                        _ if input == "1" => {
-                            section_input_ok = true;
-                            "fresh".to_string()
-                        }
-                        _ if input == "2" => {
-                            section_input_ok = true;
-                            "pantry".to_string()
-                        }
-                        _ if input == "3" => {
-                            section_input_ok = true;
-                            "protein".to_string()
-                        }
-                        _ if input == "4" => {
-                            section_input_ok = true;
-                            "dairy".to_string()
-                        }
-                        _ if input == "5" => {
-                            section_input_ok = true;
-                            "freezer".to_string()
-                        }
-                        _ => {
-                            eprintln!("re-enter section information");
-                            continue;
-                        }
-                    };
-                }
-                let section = GroceriesItemSection(section_input);
+    /// The recipes that use `item`, in library order.
+    pub fn used_in<'a>(&'a self, item: &'a ItemId) -> impl Iterator<Item = &'a RecipeRecord> {
+        self.recipes.iter().filter(move |recipe| recipe.uses(item))
+    }
+
+    /// Whether any recipe uses `item`.
+    pub fn is_recipe_ingredient(&self, item: &ItemId) -> bool {
+        self.used_in(item).next().is_some()
+    }
+
+    /// Adds the ingredients that aren't in the library yet to it and returns
+    /// them. Their section comes from `sections`, keyed by item name, or is
+    /// asked for.
+    pub fn check_recipe_ingredients(
+        &mut self,
+        ingredients: &str,
+        sections: &BTreeMap<String, String>,
+    ) -> Result<Vec<GroceriesItem>, ReadError> {
+        let ingredients = Ingredients::from_input_string(ingredients)?;
+        // each once, however often it's given
+        let mut missing: Vec<&Ingredient> = vec![];
+        for ingredient in ingredients.iter() {
+            if self.ingredient_position(&ingredient.name.0).is_err()
+                && !missing.iter().any(|x| x.name == ingredient.name)
+            {
+                missing.push(ingredient);
+            }
+        }
+        // check the sections we were given before adding anything
+        let given = |name: &str| {
+            sections
+                .get(name)
+                .map(|section| self.sections.iter().find(|s| &s.0 == section).cloned())
+        };
+        if missing
+            .iter()
+            .any(|ingredient| matches!(given(&ingredient.name.0), Some(None)))
+        {
+            return Err(ReadError::SectionNotFound);
+        }
+        let mut new_items = Vec::new();
+        for ingredient in missing {
+            let section = match given(&ingredient.name.0).flatten() {
+                Some(section) => section,
+                None => self.prompt_for_section(&ingredient.name.0)?,
+            };
+            new_items.push(GroceriesItem::new_initialized(
+                ingredient.name.clone(),
+                section,
+            ));
+        }
+        for item in new_items.iter() {
+            self.add_item(item.clone());
+        }
+        Ok(new_items)
+    }
+
+    /// Adds a recipe with its ingredients in the order they're given. They
+    /// all have to be in the library, see [`Groceries::check_recipe_ingredients`],
+    /// or nothing is added and the error names those that aren't.
+    pub fn add_recipe(&mut self, name: &str, ingredients: &str) -> Result<(), ReadError> {
+        if self.has_recipe(name) {
+            return Err(ReadError::RecipeExists);
+        }
+        let ingredients = Ingredients::from_input_string(ingredients)?;
+        let mut missing: Vec<&str> = vec![];
+        for name in ingredients
+            .iter()
+            .map(|ingredient| ingredient.name.0.as_str())
+        {
+            if self.ingredient_position(name).is_err() && !missing.contains(&name) {
+                missing.push(name);
+            }
+        }
+        if !missing.is_empty() {
+            return Err(ReadError::IngredientsNotInLibrary {
+                items: missing.join(", "),
+            });
+        }
+
+        let mut recipe = RecipeRecord::new(RecipeName(name.to_string()));
+        for ingredient in ingredients.iter() {
+            let item = self.collection[self.ingredient_position(&ingredient.name.0)?]
+                .id
+                .clone();
+            if !recipe.uses(&item) {
+                recipe.ingredients.push(RecipeIngredient {
+                    item,
+                    amount: ingredient.amount.clone(),
+                });
+            }
+        }
+        self.add_recipe_record(recipe);
+
+        Ok(())
+    }
+
+    /// Adds `recipe`, with a new id if another recipe has its id already.
+    fn add_recipe_record(&mut self, mut recipe: RecipeRecord) -> RecipeId {
+        if self.recipe_by_id(&recipe.id).is_some() {
+            recipe.id = RecipeId(new_id(&recipe.name.0, |id| {
+                self.recipes.iter().any(|x| x.id.0 == id)
+            }));
+        }
+        let id = recipe.id.clone();
+        self.recipes.push(recipe);
+        id
+    }
+
+    pub fn delete_recipe(&mut self, name: &str) -> Result<(), ReadError> {
+        if let Ok(i) = self.recipe_position(name) {
+            self.recipes.remove(i);
+        }
+        Ok(())
+    }
+
+    /// Whether the library has a recipe called `name`, in any case, as two
+    /// recipes whose names only differ in case are one too many.
+    pub fn has_recipe(&self, name: &str) -> bool {
+        self.recipes
+            .iter()
+            .any(|x| x.name.0.trim().to_lowercase() == name.trim().to_lowercase())
+    }
+
+    fn recipe_position(&self, recipe: &str) -> Result<usize, ReadError> {
+        self.recipes
+            .iter()
+            .position(|x| x.name.0 == recipe)
+            .ok_or(ReadError::RecipeNotFound)
+    }
+
+    pub fn recipe(&self, name: &str) -> Result<&RecipeRecord, ReadError> {
+        Ok(&self.recipes[self.recipe_position(name)?])
+    }
+
+    pub fn recipe_mut(&mut self, name: &str) -> Result<&mut RecipeRecord, ReadError> {
+        let i = self.recipe_position(name)?;
+        Ok(&mut self.recipes[i])
+    }
+
+    pub fn recipe_by_id(&self, id: &RecipeId) -> Option<&RecipeRecord> {
+        self.recipes.iter().find(|recipe| &recipe.id == id)
+    }
+
+    // an item's name comes before another item's alias
+    // changing or deleting an item takes its name, so an alias can't
+    // quietly stand in for the item it belongs to
+    fn item_position(&self, name: &str) -> Result<usize, ReadError> {
+        match self.collection.iter().position(|x| x.name.0 == name) {
+            Some(i) => Ok(i),
+            None => match self.alias_position(name) {
+                Some(i) => Err(ReadError::AliasNotName {
+                    alias: name.to_string(),
+                    item: self.collection[i].name.0.clone(),
+                }),
+                None => Err(ReadError::ItemNotFound),
+            },
+        }
+    }
+
+    fn alias_position(&self, name: &str) -> Option<usize> {
+        self.collection.iter().position(|x| x.has_alias(name))
+    }
+
+    // recipes find items by their aliases too, and their ingredient lists
+    // come in lower case, whatever the library calls the items
+    fn ingredient_position(&self, name: &str) -> Result<usize, ReadError> {
+        self.collection
+            .iter()
+            .position(|x| x.name.0 == name)
+            .or_else(|| self.alias_position(name))
+            .or_else(|| {
+                self.collection
+                    .iter()
+                    .position(|x| x.name.0.to_lowercase() == name.to_lowercase())
+            })
+            .ok_or(ReadError::ItemNotFound)
+    }
+
+    /// The item `name` means the way a recipe would, which may be one of
+    /// its aliases. For finding items, not changing them.
+    pub fn lookup_item(&self, name: &str) -> Result<&GroceriesItem, ReadError> {
+        Ok(&self.collection[self.ingredient_position(name)?])
+    }
+
+    /// Lets recipes, searches and the shopping list find `item` by `alias` too.
+    pub fn add_alias(&mut self, item: &str, alias: &str) -> Result<(), ReadError> {
+        let i = self.item_position(item)?;
+        let alias = alias.trim();
+        if alias.is_empty() {
+            return Err(ReadError::ParseInputError);
+        }
+        if self
+            .collection
+            .iter()
+            .any(|x| x.name.0.to_lowercase() == alias.to_lowercase() || x.has_alias(alias))
+        {
+            return Err(ReadError::ItemExists);
+        }
+        self.collection[i].aliases.push(alias.to_string());
+        Ok(())
+    }
+
+    pub fn remove_alias(&mut self, item: &str, alias: &str) -> Result<(), ReadError> {
+        let i = self.item_position(item)?;
+        let item = &mut self.collection[i];
+        if !item.has_alias(alias) {
+            return Err(ReadError::AliasNotFound);
+        }
+        let alias = alias.trim().to_lowercase();
+        item.aliases.retain(|x| x.to_lowercase() != alias);
+        Ok(())
+    }
+
+    /// Flags `item` to go on the shopping list's checklist, to look for at
+    /// home, rather than straight on the list.
+    pub fn set_check_first(&mut self, item: &str, check_first: bool) -> Result<(), ReadError> {
+        let i = self.item_position(item)?;
+        self.collection[i].check_first = check_first;
+        Ok(())
+    }
+
+    /// Items we have at home, in section order.
+    pub fn pantry(&self) -> impl Iterator<Item = &GroceriesItem> {
+        self.items().filter(|item| item.on_hand.is_some())
+    }
+
+    /// Records how much of `item` we have at home, or that we have some.
+    pub fn set_stock(&mut self, item: &str, amount: Option<Amount>) -> Result<(), ReadError> {
+        let i = self.item_position(item)?;
+        self.collection[i].on_hand = Some(amount.unwrap_or_default());
+        Ok(())
+    }
+
+    pub fn restock(&mut self, item: &str, amount: Option<&Amount>) -> Result<(), ReadError> {
+        let i = self.item_position(item)?;
+        self.collection[i].restock(amount)
+    }
+
+    pub fn use_stock(&mut self, item: &str, amount: Option<&Amount>) -> Result<(), ReadError> {
+        let i = self.item_position(item)?;
+        self.collection[i].use_up(amount)
+    }
+
+    /// Adds `ingredient`, which may carry an amount like "2 cups kale; torn",
+    /// to the end of `recipe`. An ingredient the recipe has already keeps its
+    /// place and takes the new amount, if one is given.
+    pub fn add_ingredient(&mut self, recipe: &str, ingredient: &str) -> Result<(), ReadError> {
+        let ingredient = Ingredient::from_str(ingredient)?;
+        let item = self.lookup_item(&ingredient.name.0)?.id.clone();
+        let recipe = self.recipe_mut(recipe)?;
+        match recipe.ingredients.iter_mut().find(|x| x.item == item) {
+            Some(existing) if !ingredient.amount.is_empty() => existing.amount = ingredient.amount,
+            Some(_) => {}
+            None => recipe.ingredients.push(RecipeIngredient {
+                item,
+                amount: ingredient.amount,
+            }),
+        }
+        Ok(())
+    }
+
+    pub fn delete_ingredient(&mut self, recipe: &str, ingredient: &str) -> Result<(), ReadError> {
+        let item = self.lookup_item(ingredient)?.id.clone();
+        let recipe = self.recipe_mut(recipe)?;
+        let i = recipe
+            .ingredients
+            .iter()
+            .position(|x| x.item == item)
+            .ok_or(ReadError::IngredientNotFound)?;
+        recipe.ingredients.remove(i);
+        Ok(())
+    }
+
+    /// Swaps `ingredient` for `new_ingredient` in `recipe`, in the same place.
+    /// The amount carries over unless `new_ingredient` gives one of its own.
+    pub fn edit_ingredient(
+        &mut self,
+        recipe: &str,
+        ingredient: &str,
+        new_ingredient: &str,
+    ) -> Result<(), ReadError> {
+        // check everything exists before changing anything
+        self.recipe(recipe)?;
+        let new_ingredient = Ingredient::from_str(new_ingredient)?;
+        let new_item = self.lookup_item(&new_ingredient.name.0)?.id.clone();
+        let item = self.lookup_item(ingredient)?.id.clone();
+        let recipe = self.recipe_mut(recipe)?;
+        let i = recipe
+            .ingredients
+            .iter()
+            .position(|x| x.item == item)
+            .ok_or(ReadError::IngredientNotFound)?;
+        let amount = if new_ingredient.amount.is_empty() {
+            recipe.ingredients[i].amount.clone()
+        } else {
+            new_ingredient.amount
+        };
+        // the new ingredient may be in the recipe already
+        recipe
+            .ingredients
+            .retain(|x| x.item != new_item || x.item == item);
+        let i = recipe
+            .ingredients
+            .iter()
+            .position(|x| x.item == item)
+            .unwrap_or(i);
+        recipe.ingredients[i] = RecipeIngredient {
+            item: new_item,
+            amount,
+        };
+        Ok(())
+    }
+
+    /// The items `recipe` uses, in the order the recipe lists them.
+    pub fn recipe_ingredients(&self, recipe: &str) -> impl Iterator<Item = &GroceriesItem> {
+        self.recipe(recipe)
+            .map(|recipe| recipe.ingredients.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|ingredient| self.item_by_id(&ingredient.item))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    use assert_fs::prelude::*;
+
+    fn create_test_json_file() -> Result<assert_fs::NamedTempFile, Box<dyn std::error::Error>> {
+        let file = assert_fs::NamedTempFile::new("test.json")?;
+        file.write_str(
+            r#"
+            {"sections":["fresh","pantry","protein","dairy","freezer"],"collection":[{"name":"eggs","section":"dairy","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies","fried eggs for breakfast","turkey meatballs"]},{"name":"milk","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"lemons","section":"fresh","is_recipe_ingredient":true,"recipes":["chicken breasts with lemon","hummus","sheet-pan chicken with jammy tomatoes","flue flighter chicken stew"]},{"name":"ginger","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli"]},{"name":"spinach","section":"fresh","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast","flue flighter chicken stew"]},{"name":"garlic","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas","chicken breasts with lemon","hummus","tomato pasta","crispy sheet-pan noodles","flue flighter chicken stew","sheet-pan chicken with jammy tomatoes","swordfish pasta"]},{"name":"yellow onion","section":"fresh","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew"]},{"name":"fizzy water","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"kale","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"beer","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"parsley","section":"fresh","is_recipe_ingredient":true,"recipes":["turkey meatballs","flue flighter chicken stew","sheet-pan chicken with jammy tomatoes","swordfish pasta"]},{"name":"kefir","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"kimchi","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"sour cream","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"potatoes","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"broccoli","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli"]},{"name":"asparagus","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"dill","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"red onion","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"unsalted butter","section":"dairy","is_recipe_ingredient":true,"recipes":["chicken breasts with lemon","oatmeal chocolate chip cookies","fried eggs for breakfast"]},{"name":"scallions","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas"]},{"name":"mozzarella","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"cucumbers","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"greek yogurt","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"cream cheese","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"sweet potato","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"sausages","section":"protein","is_recipe_ingredient":true,"recipes":[]},{"name":"tofu","section":"protein","is_recipe_ingredient":true,"recipes":["crispy tofu with cashews and blistered snap peas","crispy sheet-pan noodles"]},{"name":"short grain brown rice","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","flue flighter chicken stew"]},{"name":"tahini","section":"pantry","is_recipe_ingredient":true,"recipes":["hummus"]},{"name":"chicken stock","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"orzo","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"pasta","section":"pantry","is_recipe_ingredient":true,"recipes":["tomato pasta","swordfish pasta"]},{"name":"bread","section":"pantry","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast","peanut butter and jelly on toast","turkey and cheese sandwiches"]},{"name":"coffee","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"cumin","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"coconut milk (unsweetened)","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy tofu with cashews and blistered snap peas"]},{"name":"tortilla chips","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"Ritz crackers","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"black beans","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"mustard","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey and cheese sandwiches"]},{"name":"chips","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"popcorn","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"olive oil","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","chicken breasts with lemon","hummus","tomato pasta","sheet-pan chicken with jammy tomatoes","turkey meatballs","swordfish pasta"]},{"name":"honey","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas"]},{"name":"black pepper","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","sheet-pan chicken with jammy tomatoes"]},{"name":"apple cider vinegar","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"pickles","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"jasmine rice","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"rice vinegar","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas"]},{"name":"balsamic vinegar","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"vegetable oil","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy tofu with cashews and blistered snap peas","crispy sheet-pan noodles"]},{"name":"baking soda","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"mayonnaise","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey and cheese sandwiches"]},{"name":"cannellini beans","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"whole-wheat tortillas","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"dumplings","section":"freezer","is_recipe_ingredient":false,"recipes":[]},{"name":"edamame","section":"freezer","is_recipe_ingredient":false,"recipes":[]},{"name":"ice cream","section":"freezer","is_recipe_ingredient":false,"recipes":[]},{"name":"old fashioned rolled oats","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"chocolate chips","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"baking powder","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"baking soda","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"salt","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","oatmeal chocolate chip cookies","crispy sheet-pan noodles","sheet-pan chicken with jammy tomatoes"]},{"name":"white sugar","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"vanilla extract","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"whole-wheat flour","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"tomatoes","section":"fresh","is_recipe_ingredient":true,"recipes":["tomato pasta"]},{"name":"basil","section":"fresh","is_recipe_ingredient":true,"recipes":["tomato pasta"]},{"name":"parmigiana","section":"dairy","is_recipe_ingredient":true,"recipes":["tomato pasta","turkey meatballs"]},{"name":"1/2 & 1/2","section":"dairy","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast"]},{"name":"feta","section":"dairy","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast"]},{"name":"instant ramen noodles","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"sesame oil","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy sheet-pan noodles"]},{"name":"soy sauce","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas","crispy sheet-pan noodles"]},{"name":"baby bok choy","section":"fresh","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"cilantro","section":"fresh","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"hoisin","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"maple syrup","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"sesame seeds","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy sheet-pan noodles"]},{"name":"ground turkey","section":"protein","is_recipe_ingredient":true,"recipes":["turkey meatballs"]},{"name":"panko bread crumbs","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey meatballs"]},{"name":"garlic powder","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey meatballs"]},{"name":"skinless boneless chicken thighs","section":"protein","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew","sheet-pan chicken with jammy tomatoes"]},{"name":"carrots","section":"fresh","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew"]},{"name":"red pepper flakes","section":"pantry","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew","crispy tofu with cashews and blistered snap peas"]},{"name":"chicken broth","section":"pantry","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew","chicken breasts with lemon"]},{"name":"string beans","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"peaches","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"whipped cream","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"kiwi fruit","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"marscapone cheese","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"swordfish","section":"protein","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"eggplant","section":"fresh","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"tomato puree","section":"pantry","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"pine nuts","section":"pantry","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"french bread","section":"pantry","is_recipe_ingredient":false,"recipes":[]},{"name":"cayenne pepper","section":"pantry","is_recipe_ingredient":false,"recipes":[]}],"recipes":["oatmeal chocolate chip cookies","tomato pasta","fried eggs for breakfast","crispy sheet-pan noodles","turkey meatballs","flue flighter chicken stew","sheet-pan chicken with jammy tomatoes","turkey and cheese sandwiches","peanut butter and jelly on toast","cheese and apple snack","hummus","chicken breasts with lemon","crispy tofu with cashews and blistered snap peas","swordfish pasta"]}"#)?;
+        Ok(file)
+    }
+
+    #[test]
+    fn test_groceries_new() -> Result<(), Box<dyn std::error::Error>> {
+        let path = "test_groceries.json";
+        let g = Groceries::new_initialized()?;
+        g.save(path)?;
+        let g = Groceries::from_path(path)?;
+        insta::assert_json_snapshot!(g, @r###"
+        {
+          "schema_version": 4,
+          "sections": [],
+          "collection": [],
+          "recipes": []
+        }
+        "###);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_recipe() -> Result<(), Box<dyn std::error::Error>> {
+        let file = create_test_json_file()?;
+        let mut g = Groceries::from_path(file.path())?;
+        insta::assert_json_snapshot!(g.recipes, @r###"
+        [
+          {
+            "id": "oatmeal-chocolate-chip-cookies",
+            "name": "oatmeal chocolate chip cookies",
+            "ingredients": [
+              {
+                "item": "eggs"
+              },
+              {
+                "item": "unsalted-butter"
+              },
+              {
+                "item": "old-fashioned-rolled-oats"
+              },
+              {
+                "item": "chocolate-chips"
+              },
+              {
+                "item": "baking-powder"
+              },
+              {
+                "item": "baking-soda-2"
+              },
+              {
+                "item": "salt"
+              },
+              {
+                "item": "white-sugar"
+              },
+              {
+                "item": "vanilla-extract"
+              },
+              {
+                "item": "whole-wheat-flour"
+              }
+            ]
+          },
+          {
+            "id": "tomato-pasta",
+            "name": "tomato pasta",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "pasta"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "tomatoes"
+              },
+              {
+                "item": "basil"
+              },
+              {
+                "item": "parmigiana"
+              }
+            ]
+          },
+          {
+            "id": "fried-eggs-for-breakfast",
+            "name": "fried eggs for breakfast",
+            "ingredients": [
+              {
+                "item": "eggs"
+              },
+              {
+                "item": "spinach"
+              },
+              {
+                "item": "unsalted-butter"
+              },
+              {
+                "item": "bread"
+              },
+              {
+                "item": "1-2-1-2"
+              },
+              {
+                "item": "feta"
+              }
+            ]
+          },
+          {
+            "id": "crispy-sheet-pan-noodles",
+            "name": "crispy sheet-pan noodles",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "tofu"
+              },
+              {
+                "item": "vegetable-oil"
+              },
+              {
+                "item": "salt"
+              },
+              {
+                "item": "instant-ramen-noodles"
+              },
+              {
+                "item": "sesame-oil"
+              },
+              {
+                "item": "soy-sauce"
+              },
+              {
+                "item": "baby-bok-choy"
+              },
+              {
+                "item": "cilantro"
+              },
+              {
+                "item": "hoisin"
+              },
+              {
+                "item": "maple-syrup"
+              },
+              {
+                "item": "sesame-seeds"
+              }
+            ]
+          },
+          {
+            "id": "turkey-meatballs",
+            "name": "turkey meatballs",
+            "ingredients": [
+              {
+                "item": "eggs"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "parmigiana"
+              },
+              {
+                "item": "ground-turkey"
+              },
+              {
+                "item": "panko-bread-crumbs"
+              },
+              {
+                "item": "garlic-powder"
+              }
+            ]
+          },
+          {
+            "id": "flue-flighter-chicken-stew",
+            "name": "flue flighter chicken stew",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "spinach"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "yellow-onion"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "short-grain-brown-rice"
+              },
+              {
+                "item": "skinless-boneless-chicken-thighs"
+              },
+              {
+                "item": "carrots"
+              },
+              {
+                "item": "red-pepper-flakes"
+              },
+              {
+                "item": "chicken-broth"
+              }
+            ]
+          },
+          {
+            "id": "sheet-pan-chicken-with-jammy-tomatoes",
+            "name": "sheet-pan chicken with jammy tomatoes",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "black-pepper"
+              },
+              {
+                "item": "salt"
+              },
+              {
+                "item": "skinless-boneless-chicken-thighs"
+              }
+            ]
+          },
+          {
+            "id": "turkey-and-cheese-sandwiches",
+            "name": "turkey and cheese sandwiches",
+            "ingredients": [
+              {
+                "item": "bread"
+              },
+              {
+                "item": "mustard"
+              },
+              {
+                "item": "mayonnaise"
+              }
+            ]
+          },
+          {
+            "id": "peanut-butter-and-jelly-on-toast",
+            "name": "peanut butter and jelly on toast",
+            "ingredients": [
+              {
+                "item": "bread"
+              }
+            ]
+          },
+          {
+            "id": "cheese-and-apple-snack",
+            "name": "cheese and apple snack"
+          },
+          {
+            "id": "hummus",
+            "name": "hummus",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "tahini"
+              },
+              {
+                "item": "olive-oil"
+              }
+            ]
+          },
+          {
+            "id": "chicken-breasts-with-lemon",
+            "name": "chicken breasts with lemon",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "unsalted-butter"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "chicken-broth"
+              }
+            ]
+          },
+          {
+            "id": "crispy-tofu-with-cashews-and-blistered-snap-peas",
+            "name": "crispy tofu with cashews and blistered snap peas",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "scallions"
+              },
+              {
+                "item": "tofu"
+              },
+              {
+                "item": "coconut-milk-unsweetened"
+              },
+              {
+                "item": "honey"
+              },
+              {
+                "item": "rice-vinegar"
+              },
+              {
+                "item": "vegetable-oil"
+              },
+              {
+                "item": "soy-sauce"
+              },
+              {
+                "item": "red-pepper-flakes"
+              }
+            ]
+          },
+          {
+            "id": "swordfish-pasta",
+            "name": "swordfish pasta",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "pasta"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "swordfish"
+              },
+              {
+                "item": "eggplant"
+              },
+              {
+                "item": "tomato-puree"
+              },
+              {
+                "item": "pine-nuts"
+              }
+            ]
+          },
+          {
+            "id": "sheet-pan-salmon-with-broccoli",
+            "name": "Sheet Pan Salmon with Broccoli",
+            "ingredients": [
+              {
+                "item": "ginger"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "broccoli"
+              },
+              {
+                "item": "scallions"
+              },
+              {
+                "item": "short-grain-brown-rice"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "honey"
+              },
+              {
+                "item": "black-pepper"
+              },
+              {
+                "item": "rice-vinegar"
+              },
+              {
+                "item": "salt"
+              },
+              {
+                "item": "sesame-oil"
+              },
+              {
+                "item": "soy-sauce"
+              },
+              {
+                "item": "sesame-seeds"
+              }
+            ]
+          }
+        ]
+        "###);
+        g.delete_recipe("oatmeal chocolate chip cookies")?;
+        insta::assert_json_snapshot!(g.recipes, @r###"
+        [
+          {
+            "id": "tomato-pasta",
+            "name": "tomato pasta",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "pasta"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "tomatoes"
+              },
+              {
+                "item": "basil"
+              },
+              {
+                "item": "parmigiana"
+              }
+            ]
+          },
+          {
+            "id": "fried-eggs-for-breakfast",
+            "name": "fried eggs for breakfast",
+            "ingredients": [
+              {
+                "item": "eggs"
+              },
+              {
+                "item": "spinach"
+              },
+              {
+                "item": "unsalted-butter"
+              },
+              {
+                "item": "bread"
+              },
+              {
+                "item": "1-2-1-2"
+              },
+              {
+                "item": "feta"
+              }
+            ]
+          },
+          {
+            "id": "crispy-sheet-pan-noodles",
+            "name": "crispy sheet-pan noodles",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "tofu"
+              },
+              {
+                "item": "vegetable-oil"
+              },
+              {
+                "item": "salt"
+              },
+              {
+                "item": "instant-ramen-noodles"
+              },
+              {
+                "item": "sesame-oil"
+              },
+              {
+                "item": "soy-sauce"
+              },
+              {
+                "item": "baby-bok-choy"
+              },
+              {
+                "item": "cilantro"
+              },
+              {
+                "item": "hoisin"
+              },
+              {
+                "item": "maple-syrup"
+              },
+              {
+                "item": "sesame-seeds"
+              }
+            ]
+          },
+          {
+            "id": "turkey-meatballs",
+            "name": "turkey meatballs",
+            "ingredients": [
+              {
+                "item": "eggs"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "parmigiana"
+              },
+              {
+                "item": "ground-turkey"
+              },
+              {
+                "item": "panko-bread-crumbs"
+              },
+              {
+                "item": "garlic-powder"
+              }
+            ]
+          },
+          {
+            "id": "flue-flighter-chicken-stew",
+            "name": "flue flighter chicken stew",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "spinach"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "yellow-onion"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "short-grain-brown-rice"
+              },
+              {
+                "item": "skinless-boneless-chicken-thighs"
+              },
+              {
+                "item": "carrots"
+              },
+              {
+                "item": "red-pepper-flakes"
+              },
+              {
+                "item": "chicken-broth"
+              }
+            ]
+          },
+          {
+            "id": "sheet-pan-chicken-with-jammy-tomatoes",
+            "name": "sheet-pan chicken with jammy tomatoes",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "black-pepper"
+              },
+              {
+                "item": "salt"
+              },
+              {
+                "item": "skinless-boneless-chicken-thighs"
+              }
+            ]
+          },
+          {
+            "id": "turkey-and-cheese-sandwiches",
+            "name": "turkey and cheese sandwiches",
+            "ingredients": [
+              {
+                "item": "bread"
+              },
+              {
+                "item": "mustard"
+              },
+              {
+                "item": "mayonnaise"
+              }
+            ]
+          },
+          {
+            "id": "peanut-butter-and-jelly-on-toast",
+            "name": "peanut butter and jelly on toast",
+            "ingredients": [
+              {
+                "item": "bread"
+              }
+            ]
+          },
+          {
+            "id": "cheese-and-apple-snack",
+            "name": "cheese and apple snack"
+          },
+          {
+            "id": "hummus",
+            "name": "hummus",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "tahini"
+              },
+              {
+                "item": "olive-oil"
+              }
+            ]
+          },
+          {
+            "id": "chicken-breasts-with-lemon",
+            "name": "chicken breasts with lemon",
+            "ingredients": [
+              {
+                "item": "lemons"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "unsalted-butter"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "chicken-broth"
+              }
+            ]
+          },
+          {
+            "id": "crispy-tofu-with-cashews-and-blistered-snap-peas",
+            "name": "crispy tofu with cashews and blistered snap peas",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "scallions"
+              },
+              {
+                "item": "tofu"
+              },
+              {
+                "item": "coconut-milk-unsweetened"
+              },
+              {
+                "item": "honey"
+              },
+              {
+                "item": "rice-vinegar"
+              },
+              {
+                "item": "vegetable-oil"
+              },
+              {
+                "item": "soy-sauce"
+              },
+              {
+                "item": "red-pepper-flakes"
+              }
+            ]
+          },
+          {
+            "id": "swordfish-pasta",
+            "name": "swordfish pasta",
+            "ingredients": [
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "parsley"
+              },
+              {
+                "item": "pasta"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "swordfish"
+              },
+              {
+                "item": "eggplant"
+              },
+              {
+                "item": "tomato-puree"
+              },
+              {
+                "item": "pine-nuts"
+              }
+            ]
+          },
+          {
+            "id": "sheet-pan-salmon-with-broccoli",
+            "name": "Sheet Pan Salmon with Broccoli",
+            "ingredients": [
+              {
+                "item": "ginger"
+              },
+              {
+                "item": "garlic"
+              },
+              {
+                "item": "broccoli"
+              },
+              {
+                "item": "scallions"
+              },
+              {
+                "item": "short-grain-brown-rice"
+              },
+              {
+                "item": "olive-oil"
+              },
+              {
+                "item": "honey"
+              },
+              {
+                "item": "black-pepper"
+              },
+              {
+                "item": "rice-vinegar"
+              },
+              {
+                "item": "salt"
+              },
+              {
+                "item": "sesame-oil"
+              },
+              {
+                "item": "soy-sauce"
+              },
+              {
+                "item": "sesame-seeds"
+              }
+            ]
+          }
+        ]
+        "###);
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_recipe_ingredients() -> Result<(), Box<dyn std::error::Error>> {
+        let file = create_test_json_file()?;
+        let mut g = Groceries::from_path(file.path())?;
+        let ingredients = |g: &Groceries| {
+            g.recipe_ingredients("tomato pasta")
+                .map(|item| item.name.0.clone())
+                .collect::<Vec<_>>()
+        };
+        insta::assert_json_snapshot!(ingredients(&g), @r###"
+        [
+          "garlic",
+          "pasta",
+          "olive oil",
+          "tomatoes",
+          "basil",
+          "parmigiana"
+        ]
+        "###);
+
+        g.add_ingredient("tomato pasta", "kale")?;
+        g.delete_ingredient("tomato pasta", "basil")?;
+        g.edit_ingredient("tomato pasta", "parmigiana", "feta")?;
+        insta::assert_json_snapshot!(ingredients(&g), @r###"
+        [
+          "garlic",
+          "pasta",
+          "olive oil",
+          "tomatoes",
+          "feta",
+          "kale"
+        ]
+        "###);
+
+        let used = |g: &Groceries, name: &str| g.is_recipe_ingredient(&g.item(name).unwrap().id);
+        assert!(used(&g, "kale"));
+        assert!(!used(&g, "basil"));
+        // still used in "turkey meatballs"
+        assert!(used(&g, "parmigiana"));
+
+        assert!(matches!(
+            g.add_ingredient("no such recipe", "kale"),
+            Err(ReadError::RecipeNotFound)
+        ));
+        assert!(matches!(
+            g.edit_ingredient("tomato pasta", "garlic", "no such item"),
+            Err(ReadError::ItemNotFound)
+        ));
+        assert!(ingredients(&g).contains(&"garlic".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_amounts() -> Result<(), Box<dyn std::error::Error>> {
+        let file = create_test_json_file()?;
+        let mut g = Groceries::from_path(file.path())?;
+        g.add_recipe(
+            "feta eggs",
+            "3 eggs, 100 g of feta; crumbled, 1/2 & 1/2, spinach; to taste",
+        )?;
+        let amounts = |g: &Groceries| {
+            let recipe = g.recipe("feta eggs").unwrap();
+            g.recipe_ingredients("feta eggs")
+                .map(|item| match recipe.amount(&item.id) {
+                    Some(amount) => format!("{} — {}", item.name, amount),
+                    None => item.name.to_string(),
+                })
+                .collect::<Vec<_>>()
+        };
+        insta::assert_json_snapshot!(amounts(&g), @r###"
+        [
+          "eggs — 3",
+          "feta — 100 g, crumbled",
+          "1/2 & 1/2",
+          "spinach — to taste"
+        ]
+        "###);
+
+        // amounts are kept on saving
+        g.save(file.path())?;
+        let mut g = Groceries::from_path(file.path())?;
+        assert_eq!(
+            g.recipe("feta eggs")?.amount(&g.item("feta")?.id),
+            Some(&crate::Amount {
+                quantity: Some(100.0),
+                unit: Some("g".to_string()),
+                preparation: Some("crumbled".to_string()),
+            })
+        );
+
+        g.edit_ingredient("feta eggs", "feta", "parmigiana")?;
+        g.edit_ingredient("feta eggs", "eggs", "2 eggs")?;
+        g.add_ingredient("feta eggs", "1 tbsp olive oil")?;
+        g.delete_ingredient("feta eggs", "spinach")?;
+        insta::assert_json_snapshot!(amounts(&g), @r###"
+        [
+          "eggs — 2",
+          "parmigiana — 100 g, crumbled",
+          "1/2 & 1/2",
+          "olive oil — 1 tbsp"
+        ]
+        "###);
+        assert_eq!(g.recipe("feta eggs")?.amount(&g.item("spinach")?.id), None);
+
+        // deleting an item takes it out of its recipes
+        g.delete_item("1/2 & 1/2")?;
+        assert_eq!(g.recipe_ingredients("feta eggs").count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recipe_details() -> Result<(), Box<dyn std::error::Error>> {
+        let file = create_test_json_file()?;
+        let mut g = Groceries::from_path(file.path())?;
+        // recipes saved as plain names are upgraded to records
+        assert_eq!(
+            RecipeRecord {
+                ingredients: vec![],
+                ..g.recipe("hummus")?.clone()
+            },
+            RecipeRecord::new(RecipeName("hummus".to_string()))
+        );
+
+        let recipe = g.recipe_mut("hummus")?;
+        recipe.servings = Some(4);
+        recipe.prep_time = Some(15);
+        recipe.tags = vec!["vegetarian".to_string()];
+        recipe.source = Some("https://example.com/hummus".to_string());
+        recipe.add_instruction("blend", None)?;
+        recipe.add_instruction("drain the chickpeas", Some(1))?;
+        recipe.add_instruction("serve", None)?;
+        recipe.delete_instruction(3)?;
+        assert!(matches!(
+            recipe.add_instruction("garnish", Some(4)),
+            Err(ReadError::StepNotFound)
+        ));
+        assert!(matches!(
+            recipe.delete_instruction(0),
+            Err(ReadError::StepNotFound)
+        ));
+
+        g.save(file.path())?;
+        let g = Groceries::from_path(file.path())?;
+        insta::assert_json_snapshot!(g.recipe("hummus")?, @r###"
+        {
+          "id": "hummus",
+          "name": "hummus",
+          "ingredients": [
+            {
+              "item": "lemons"
+            },
+            {
+              "item": "garlic"
+            },
+            {
+              "item": "tahini"
+            },
+            {
+              "item": "olive-oil"
+            }
+          ],
+          "servings": 4,
+          "prep_time": 15,
+          "instructions": [
+            "drain the chickpeas",
+            "blend"
+          ],
+          "tags": [
+            "vegetarian"
+          ],
+          "source": "https://example.com/hummus"
+        }
+        "###);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sections() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::new_initialized()?;
+        g.add_item(GroceriesItem::new("bagels", "bakery"));
+        g.fill_sections(&["fresh".to_string(), "dairy".to_string()]);
+        g.add_item(GroceriesItem::new("kale", "fresh"));
+        g.add_item(GroceriesItem::new("milk", "dairy"));
+        let sections = |g: &Groceries| {
+            g.sections
+                .iter()
+                .map(|section| section.0.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sections(&g), ["fresh", "dairy", "bakery"]);
+        // items come in section order
+        assert_eq!(
+            g.items()
+                .map(|item| item.name.0.as_str())
+                .collect::<Vec<_>>(),
+            ["kale", "milk", "bagels"]
+        );
 
-                let item = GroceriesItem::new_initialized(ingredient.clone(), section);
+        g.add_section("frozen", Some(2))?;
+        g.rename_section("dairy", "dairy case")?;
+        g.reorder_sections(&["bakery", "fresh"])?;
+        assert_eq!(sections(&g), ["bakery", "fresh", "frozen", "dairy case"]);
+        assert_eq!(
+            g.collection[g.item_position("milk")?].section.0,
+            "dairy case"
+        );
 
-                self.add_item(item);
-            }
-        }
+        assert!(matches!(
+            g.add_section("fresh", None),
+            Err(ReadError::SectionExists)
+        ));
+        assert!(matches!(
+            g.remove_section("bakery", None),
+            Err(ReadError::SectionNotEmpty)
+        ));
+        g.remove_section("frozen", None)?;
+        g.remove_section("bakery", Some("fresh"))?;
+        assert_eq!(sections(&g), ["fresh", "dairy case"]);
+        assert_eq!(g.collection[g.item_position("bagels")?].section.0, "fresh");
+        assert!(matches!(
+            g.reorder_sections(&["bakery"]),
+            Err(ReadError::SectionNotFound)
+        ));
         Ok(())
     }
 
-    pub fn add_recipe(&mut self, name: &str, ingredients: &str) -> Result<(), ReadError> {
-        let recipe = Recipe(name.to_string());
-
-        let ingredients = Ingredients::from_input_string(ingredients)?;
+    #[test]
+    fn test_stores() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::new_initialized()?;
+        g.fill_sections(&["fresh".to_string(), "dairy".to_string()]);
+        g.add_item(GroceriesItem::new("kale", "fresh"));
+        g.add_item(GroceriesItem::new("milk", "dairy"));
+        g.add_store("farmers market")?;
+        assert!(matches!(
+            g.add_store("farmers market"),
+            Err(ReadError::StoreExists)
+        ));
 
-        self.collection
-            .iter_mut()
-            .filter(|x| ingredients.contains(&x.name))
-            .for_each(|mut x| {
-                if !x.is_recipe_ingredient {
-                    x.is_recipe_ingredient = true;
-                }
-                x.recipes.push(recipe.clone());
-            });
+        g.set_store_aisles("farmers market", &["milk", "fresh"])?;
+        g.set_store_carries("farmers market", &["fresh", "milk"])?;
+        assert!(matches!(
+            g.set_store_carries("farmers market", &["bagels"]),
+            Err(ReadError::NotSectionOrItem)
+        ));
+        assert!(matches!(
+            g.set_store_aisles("bulk store", &["fresh"]),
+            Err(ReadError::StoreNotFound)
+        ));
 
-        self.recipes.push(recipe);
+        // stores follow the library's sections and items
+        g.rename_section("fresh", "produce")?;
+        g.delete_item("milk")?;
+        insta::assert_json_snapshot!(g.stores, @r###"
+        [
+          {
+            "name": "farmers market",
+            "aisles": [
+              {
+                "section": "produce"
+              }
+            ],
+            "carries": [
+              {
+                "section": "produce"
+              }
+            ]
+          }
+        ]
+        "###);
 
+        g.remove_store("farmers market")?;
+        assert!(g.stores.is_empty());
         Ok(())
     }
 
-    pub fn delete_recipe(&mut self, name: &str) -> Result<(), ReadError> {
-        if let Ok(i) = self
-            .recipes
-            .iter()
-            .position(|Recipe(x)| x.as_str() == name)
-            .ok_or(ReadError::ItemNotFound)
-        {
-            self.recipes.remove(i);
-        }
-        for item in self.collection.iter_mut() {
-            if let Some(i) = item.recipes.iter().position(|Recipe(x)| x.as_str() == name) {
-                item.recipes.remove(i);
-                if item.recipes.is_empty() {
-                    item.is_recipe_ingredient = false;
-                }
-            }
-        }
+    #[test]
+    fn test_item_matches() -> Result<(), Box<dyn std::error::Error>> {
+        let file = create_test_json_file()?;
+        let g = Groceries::from_path(file.path())?;
+        let names = |query: &str| {
+            g.get_item_matches(query)
+                .map(|item| item.name.0.as_str())
+                .collect::<Vec<_>>()
+        };
+        insta::assert_json_snapshot!(names("tomatoe"), @r###"
+        [
+          "tomatoes"
+        ]
+        "###);
+        insta::assert_json_snapshot!(names("eggs"), @r###"
+        [
+          "eggs"
+        ]
+        "###);
+        insta::assert_json_snapshot!(names("lemon"), @r###"
+        [
+          "lemons"
+        ]
+        "###);
+        assert!(names("xylophone").is_empty());
+        let recipes: Vec<&str> = g
+            .get_recipe_matches("turkey meatball")
+            .map(|recipe| recipe.name.0.as_str())
+            .collect();
+        insta::assert_json_snapshot!(recipes, @r###"
+        [
+          "turkey meatballs"
+        ]
+        "###);
         Ok(())
     }
 
-    pub fn recipe_ingredients(&self, recipe: &str) -> impl Iterator<Item = &GroceriesItem> {
-        self
-            .collection
-            .iter()
-            .filter(|item| item.recipes.contains(&Recipe(recipe.to_string())))
-            .collect::<Vec<_>>()
-            .into_iter()
-    }
-}
+    #[test]
+    fn test_check_recipe_ingredients() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::new_initialized()?;
+        g.fill_sections(&["fresh".to_string(), "pantry".to_string()]);
+        g.add_item_to_section("garlic", "fresh")?;
+        let ingredients = "2 cans chickpeas, 1 clove garlic, 1/2 cup tahini";
 
-#[cfg(test)]
-pub mod test {
-    use super::*;
+        let mut sections = BTreeMap::new();
+        sections.insert("chickpeas".to_string(), "pantry".to_string());
+        sections.insert("tahini".to_string(), "dry goods".to_string());
+        assert!(matches!(
+            g.check_recipe_ingredients(ingredients, &sections),
+            Err(ReadError::SectionNotFound)
+        ));
+        assert_eq!(g.items().count(), 1);
 
-    use assert_fs::prelude::*;
+        sections.insert("tahini".to_string(), "pantry".to_string());
+        let new_items = g.check_recipe_ingredients(ingredients, &sections)?;
+        let names: Vec<&str> = new_items.iter().map(|item| item.name.0.as_str()).collect();
+        assert_eq!(names, ["chickpeas", "tahini"]);
+        g.add_recipe("hummus", ingredients)?;
+        let names: Vec<_> = g
+            .recipe_ingredients("hummus")
+            .map(|item| &item.name)
+            .collect();
+        insta::assert_json_snapshot!(names, @r###"
+        [
+          "chickpeas",
+          "garlic",
+          "tahini"
+        ]
+        "###);
+        assert!(g
+            .check_recipe_ingredients(ingredients, &BTreeMap::new())?
+            .is_empty());
 
-    fn create_test_json_file() -> Result<assert_fs::NamedTempFile, Box<dyn std::error::Error>> {
-        let file = assert_fs::NamedTempFile::new("test.json")?;
-        file.write_str(
-            r#"
-            {"sections":["fresh","pantry","protein","dairy","freezer"],"collection":[{"name":"eggs","section":"dairy","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies","fried eggs for breakfast","turkey meatballs"]},{"name":"milk","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"lemons","section":"fresh","is_recipe_ingredient":true,"recipes":["chicken breasts with lemon","hummus","sheet-pan chicken with jammy tomatoes","flue flighter chicken stew"]},{"name":"ginger","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli"]},{"name":"spinach","section":"fresh","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast","flue flighter chicken stew"]},{"name":"garlic","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas","chicken breasts with lemon","hummus","tomato pasta","crispy sheet-pan noodles","flue flighter chicken stew","sheet-pan chicken with jammy tomatoes","swordfish pasta"]},{"name":"yellow onion","section":"fresh","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew"]},{"name":"fizzy water","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"kale","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"beer","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"parsley","section":"fresh","is_recipe_ingredient":true,"recipes":["turkey meatballs","flue flighter chicken stew","sheet-pan chicken with jammy tomatoes","swordfish pasta"]},{"name":"kefir","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"kimchi","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"sour cream","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"potatoes","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"broccoli","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli"]},{"name":"asparagus","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"dill","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"red onion","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"unsalted butter","section":"dairy","is_recipe_ingredient":true,"recipes":["chicken breasts with lemon","oatmeal chocolate chip cookies","fried eggs for breakfast"]},{"name":"scallions","section":"fresh","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas"]},{"name":"mozzarella","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"cucumbers","section":"fresh","is_recipe_ingredient":true,"recipes":[]},{"name":"greek yogurt","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"cream cheese","section":"dairy","is_recipe_ingredient":true,"recipes":[]},{"name":"sweet potato","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"sausages","section":"protein","is_recipe_ingredient":true,"recipes":[]},{"name":"tofu","section":"protein","is_recipe_ingredient":true,"recipes":["crispy tofu with cashews and blistered snap peas","crispy sheet-pan noodles"]},{"name":"short grain brown rice","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","flue flighter chicken stew"]},{"name":"tahini","section":"pantry","is_recipe_ingredient":true,"recipes":["hummus"]},{"name":"chicken stock","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"orzo","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"pasta","section":"pantry","is_recipe_ingredient":true,"recipes":["tomato pasta","swordfish pasta"]},{"name":"bread","section":"pantry","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast","peanut butter and jelly on toast","turkey and cheese sandwiches"]},{"name":"coffee","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"cumin","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"coconut milk (unsweetened)","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy tofu with cashews and blistered snap peas"]},{"name":"tortilla chips","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"Ritz crackers","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"black beans","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"mustard","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey and cheese sandwiches"]},{"name":"chips","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"popcorn","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"olive oil","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","chicken breasts with lemon","hummus","tomato pasta","sheet-pan chicken with jammy tomatoes","turkey meatballs","swordfish pasta"]},{"name":"honey","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas"]},{"name":"black pepper","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","sheet-pan chicken with jammy tomatoes"]},{"name":"apple cider vinegar","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"pickles","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"jasmine rice","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"rice vinegar","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas"]},{"name":"balsamic vinegar","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"vegetable oil","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy tofu with cashews and blistered snap peas","crispy sheet-pan noodles"]},{"name":"baking soda","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"mayonnaise","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey and cheese sandwiches"]},{"name":"cannellini beans","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"whole-wheat tortillas","section":"pantry","is_recipe_ingredient":true,"recipes":[]},{"name":"dumplings","section":"freezer","is_recipe_ingredient":false,"recipes":[]},{"name":"edamame","section":"freezer","is_recipe_ingredient":false,"recipes":[]},{"name":"ice cream","section":"freezer","is_recipe_ingredient":false,"recipes":[]},{"name":"old fashioned rolled oats","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"chocolate chips","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"baking powder","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"baking soda","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"salt","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","oatmeal chocolate chip cookies","crispy sheet-pan noodles","sheet-pan chicken with jammy tomatoes"]},{"name":"white sugar","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"vanilla extract","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"whole-wheat flour","section":"pantry","is_recipe_ingredient":true,"recipes":["oatmeal chocolate chip cookies"]},{"name":"tomatoes","section":"fresh","is_recipe_ingredient":true,"recipes":["tomato pasta"]},{"name":"basil","section":"fresh","is_recipe_ingredient":true,"recipes":["tomato pasta"]},{"name":"parmigiana","section":"dairy","is_recipe_ingredient":true,"recipes":["tomato pasta","turkey meatballs"]},{"name":"1/2 & 1/2","section":"dairy","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast"]},{"name":"feta","section":"dairy","is_recipe_ingredient":true,"recipes":["fried eggs for breakfast"]},{"name":"instant ramen noodles","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"sesame oil","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy sheet-pan noodles"]},{"name":"soy sauce","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy tofu with cashews and blistered snap peas","crispy sheet-pan noodles"]},{"name":"baby bok choy","section":"fresh","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"cilantro","section":"fresh","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"hoisin","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"maple syrup","section":"pantry","is_recipe_ingredient":true,"recipes":["crispy sheet-pan noodles"]},{"name":"sesame seeds","section":"pantry","is_recipe_ingredient":true,"recipes":["Sheet Pan Salmon with Broccoli","crispy sheet-pan noodles"]},{"name":"ground turkey","section":"protein","is_recipe_ingredient":true,"recipes":["turkey meatballs"]},{"name":"panko bread crumbs","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey meatballs"]},{"name":"garlic powder","section":"pantry","is_recipe_ingredient":true,"recipes":["turkey meatballs"]},{"name":"skinless boneless chicken thighs","section":"protein","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew","sheet-pan chicken with jammy tomatoes"]},{"name":"carrots","section":"fresh","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew"]},{"name":"red pepper flakes","section":"pantry","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew","crispy tofu with cashews and blistered snap peas"]},{"name":"chicken broth","section":"pantry","is_recipe_ingredient":true,"recipes":["flue flighter chicken stew","chicken breasts with lemon"]},{"name":"string beans","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"peaches","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"whipped cream","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"kiwi fruit","section":"fresh","is_recipe_ingredient":false,"recipes":[]},{"name":"marscapone cheese","section":"dairy","is_recipe_ingredient":false,"recipes":[]},{"name":"swordfish","section":"protein","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"eggplant","section":"fresh","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"tomato puree","section":"pantry","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"pine nuts","section":"pantry","is_recipe_ingredient":true,"recipes":["swordfish pasta"]},{"name":"french bread","section":"pantry","is_recipe_ingredient":false,"recipes":[]},{"name":"cayenne pepper","section":"pantry","is_recipe_ingredient":false,"recipes":[]}],"recipes":["oatmeal chocolate chip cookies","tomato pasta","fried eggs for breakfast","crispy sheet-pan noodles","turkey meatballs","flue flighter chicken stew","sheet-pan chicken with jammy tomatoes","turkey and cheese sandwiches","peanut butter and jelly on toast","cheese and apple snack","hummus","chicken breasts with lemon","crispy tofu with cashews and blistered snap peas","swordfish pasta"]}"#)?;
-        Ok(file)
+        // given twice, or with a comma too many, it's still one new item
+        let ingredients = "kumquat, 2 kumquat, garlic,";
+        assert!(matches!(
+            g.add_recipe("kumquat chutney", ingredients),
+            Err(ReadError::IngredientsNotInLibrary { items }) if items == "kumquat"
+        ));
+        sections.insert("kumquat".to_string(), "fresh".to_string());
+        let new_items = g.check_recipe_ingredients(ingredients, &sections)?;
+        let ids: Vec<&str> = new_items.iter().map(|item| item.id.0.as_str()).collect();
+        assert_eq!(ids, ["kumquat"]);
+        g.add_recipe("kumquat chutney", ingredients)?;
+        assert_eq!(g.recipe_ingredients("kumquat chutney").count(), 2);
+
+        // a name the library has already, in any case, isn't added again
+        for name in ["hummus", "Hummus "] {
+            assert!(matches!(
+                g.add_recipe(name, "garlic"),
+                Err(ReadError::RecipeExists)
+            ));
+        }
+        assert_eq!(g.recipes.len(), 2);
+        Ok(())
     }
 
     #[test]
-    fn test_groceries_new() -> Result<(), Box<dyn std::error::Error>> {
-        let path = "test_groceries.json";
-        let g = Groceries::new_initialized()?;
-        g.save(path)?;
-        let g = Groceries::from_path(path)?;
-        insta::assert_json_snapshot!(g, @r###"
-      {
-        "sections": [],
-        "collection": [],
-        "recipes": []
-      }
-      "###);
-        std::fs::remove_file(path)?;
+    fn test_duplicates() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::new_initialized()?;
+        for (name, section) in [
+            ("olive oil", "pantry"),
+            ("tomatoes", "fresh"),
+            ("parmigiana", "dairy"),
+            ("basil", "fresh"),
+            ("Parmigiano", "dairy"),
+            ("parmesan", "dairy"),
+            ("tomatos", "fresh"),
+            ("Olive Oil", "pantry"),
+        ] {
+            g.add_item(GroceriesItem::new(name, section));
+        }
+        let duplicates = |g: &Groceries| -> Vec<Vec<String>> {
+            g.duplicates()
+                .iter()
+                .map(|items| items.iter().map(|item| item.name.0.clone()).collect())
+                .collect()
+        };
+        // "parmesan" is only about half like "parmigiana", so it's left out
+        insta::assert_json_snapshot!(duplicates(&g), @r###"
+        [
+          [
+            "olive oil",
+            "Olive Oil"
+          ],
+          [
+            "tomatoes",
+            "tomatos"
+          ],
+          [
+            "parmigiana",
+            "Parmigiano"
+          ]
+        ]
+        "###);
+
+        // unless one of them has an alias like it
+        g.add_alias("Parmigiano", "parmesano")?;
+        insta::assert_json_snapshot!(duplicates(&g)[2], @r###"
+        [
+          "parmigiana",
+          "Parmigiano",
+          "parmesan"
+        ]
+        "###);
         Ok(())
     }
 
     #[test]
-    fn test_delete_recipe() -> Result<(), Box<dyn std::error::Error>> {
-        let file = create_test_json_file()?;
-        let mut g = Groceries::from_path(file.path())?;
-        insta::assert_json_snapshot!(g.recipes, @r###"
+    fn test_aliases() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::new_initialized()?;
+        g.fill_sections(&["fresh".to_string()]);
+        g.add_item_to_section("scallions", "fresh")?;
+        g.add_item_to_section("cilantro", "fresh")?;
+        g.add_item_to_section("spring onion", "fresh")?;
+        g.add_alias("scallions", "spring onions")?;
+        g.add_alias("cilantro", "coriander")?;
+        assert!(matches!(
+            g.add_alias("scallions", "Coriander"),
+            Err(ReadError::ItemExists)
+        ));
+        assert!(matches!(
+            g.add_alias("scallions", "cilantro"),
+            Err(ReadError::ItemExists)
+        ));
+
+        g.add_item_to_section("lime", "fresh")?;
+        g.add_recipe("salsa", "2 spring onions, coriander, lime")?;
+        let ingredients: Vec<String> = g
+            .recipe_ingredients("salsa")
+            .map(|item| {
+                let amount = g.recipe("salsa").unwrap().amount(&item.id);
+                format!(
+                    "{} {:?}",
+                    item.name,
+                    amount.map(|amount| amount.to_string())
+                )
+            })
+            .collect();
+        insta::assert_json_snapshot!(ingredients, @r###"
         [
-          "oatmeal chocolate chip cookies",
-          "tomato pasta",
-          "fried eggs for breakfast",
-          "crispy sheet-pan noodles",
-          "turkey meatballs",
-          "flue flighter chicken stew",
-          "sheet-pan chicken with jammy tomatoes",
-          "turkey and cheese sandwiches",
-          "peanut butter and jelly on toast",
-          "cheese and apple snack",
-          "hummus",
-          "chicken breasts with lemon",
-          "crispy tofu with cashews and blistered snap peas",
-          "swordfish pasta"
+          "scallions Some(\"2\")",
+          "cilantro None",
+          "lime None"
         ]
         "###);
-        g.delete_recipe("oatmeal chocolate chip cookies")?;
-        insta::assert_json_snapshot!(g.recipes, @r###"
+
+        let found: Vec<&str> = g
+            .get_item_matches("corriander")
+            .map(|item| item.name.0.as_str())
+            .collect();
+        assert_eq!(found, vec!["cilantro"]);
+        assert_eq!(g.lookup_item("coriander")?.name.0, "cilantro");
+        // changing or deleting an item takes its name
+        for result in [
+            g.delete_item("coriander"),
+            g.move_item("coriander", "fresh"),
+            g.rename_item("coriander", "parsley"),
+        ] {
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "'coriander' is another name for 'cilantro', use its name"
+            );
+        }
+        assert!(matches!(
+            g.add_item_to_section("coriander", "fresh"),
+            Err(ReadError::ItemExists)
+        ));
+        let duplicates: Vec<Vec<&str>> = g
+            .duplicates()
+            .iter()
+            .map(|items| items.iter().map(|item| item.name.0.as_str()).collect())
+            .collect();
+        insta::assert_json_snapshot!(duplicates, @r###"
+        [
+          [
+            "scallions",
+            "spring onion"
+          ]
+        ]
+        "###);
+
+        g.merge_items("scallions", &["spring onion"])?;
+        g.rename_item("scallions", "Spring Onions")?;
+        assert!(matches!(
+            g.remove_alias("Spring Onions", "leeks"),
+            Err(ReadError::AliasNotFound)
+        ));
+        g.remove_alias("Spring Onions", "spring onion")?;
+        g.add_alias("cilantro", "Chinese parsley")?;
+        insta::assert_json_snapshot!(g.collection, @r###"
         [
-          "tomato pasta",
-          "fried eggs for breakfast",
-          "crispy sheet-pan noodles",
-          "turkey meatballs",
-          "flue flighter chicken stew",
-          "sheet-pan chicken with jammy tomatoes",
-          "turkey and cheese sandwiches",
-          "peanut butter and jelly on toast",
-          "cheese and apple snack",
-          "hummus",
-          "chicken breasts with lemon",
-          "crispy tofu with cashews and blistered snap peas",
-          "swordfish pasta"
+          {
+            "id": "scallions",
+            "name": "Spring Onions",
+            "section": "fresh",
+            "aliases": [
+              "scallions"
+            ]
+          },
+          {
+            "id": "cilantro",
+            "name": "cilantro",
+            "section": "fresh",
+            "aliases": [
+              "coriander",
+              "Chinese parsley"
+            ]
+          },
+          {
+            "id": "lime",
+            "name": "lime",
+            "section": "fresh"
+          }
         ]
         "###);
         Ok(())
     }
 
+    #[test]
+    fn test_manage_items() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::new_initialized()?;
+        g.fill_sections(&["fresh".to_string(), "pantry".to_string()]);
+        g.add_item_to_section("chickpeas", "pantry")?;
+        g.add_item_to_section("garbanzo", "fresh")?;
+        g.add_recipe("hummus", "2 cans chickpeas")?;
+        g.add_store("bulk store")?;
+        g.set_store_carries("bulk store", &["chickpeas"])?;
+        assert!(matches!(
+            g.add_item_to_section("chickpeas", "pantry"),
+            Err(ReadError::ItemExists)
+        ));
+        assert!(matches!(
+            g.add_item_to_section("kale", "produce"),
+            Err(ReadError::SectionNotFound)
+        ));
+
+        assert!(matches!(
+            g.rename_item("chickpeas", "garbanzo"),
+            Err(ReadError::ItemExists)
+        ));
+        g.delete_item("garbanzo")?;
+        assert!(matches!(
+            g.delete_item("garbanzo"),
+            Err(ReadError::ItemNotFound)
+        ));
+        g.rename_item("chickpeas", "garbanzo beans")?;
+        g.move_item("garbanzo beans", "fresh")?;
+        let names: Vec<String> = g
+            .recipe_ingredients("hummus")
+            .map(|item| {
+                let amount = g.recipe("hummus").unwrap().amount(&item.id).unwrap();
+                format!("{} {} {}", item.name, item.section, amount)
+            })
+            .collect();
+        assert_eq!(names, ["garbanzo beans fresh 2 cans"]);
+        // the store knows it by its id, whatever it's called
+        let item = g.item("garbanzo beans")?;
+        assert_eq!(
+            g.store("bulk store")?.carries,
+            [StoreEntry::Item(item.id.clone())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pantry() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = Groceries::new_initialized()?;
+        g.add_item(GroceriesItem::new("chickpeas", "pantry"));
+        g.add_item(GroceriesItem::new("salt", "pantry"));
+        g.add_item(GroceriesItem::new("flour", "pantry"));
+        g.fill_sections(&[]);
+        let on_hand = |g: &Groceries| {
+            g.pantry()
+                .map(|item| format!("{} {}", item.name, item.on_hand.clone().unwrap_or_default()))
+                .collect::<Vec<_>>()
+        };
+
+        g.set_stock("chickpeas", Some("2 cans".parse()?))?;
+        g.restock("chickpeas", Some(&"3 cans".parse()?))?;
+        g.use_stock("chickpeas", Some(&"1 can".parse()?))?;
+        g.restock("salt", None)?;
+        g.set_stock("flour", Some("1 kg".parse()?))?;
+        g.use_stock("flour", Some(&"250 g".parse()?))?;
+        assert_eq!(on_hand(&g), ["chickpeas 4 cans", "salt ", "flour 3/4 kg"]);
+
+        assert!(matches!(
+            g.use_stock("flour", Some(&"1 cup".parse()?)),
+            Err(ReadError::UnitMismatch)
+        ));
+        g.use_stock("flour", Some(&"1 kg".parse()?))?;
+        g.use_stock("salt", None)?;
+        assert_eq!(on_hand(&g), ["chickpeas 4 cans"]);
+        assert!(matches!(
+            g.use_stock("salt", None),
+            Err(ReadError::NotInPantry)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_delete_item() -> Result<(), Box<dyn std::error::Error>> {
         let file = create_test_json_file()?;
@@ -252,748 +2185,494 @@ pub mod test {
         insta::assert_json_snapshot!(g.collection, @r###"
         [
           {
+            "id": "eggs",
             "name": "eggs",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies",
-              "fried eggs for breakfast",
-              "turkey meatballs"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "milk",
             "name": "milk",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "lemons",
             "name": "lemons",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "chicken breasts with lemon",
-              "hummus",
-              "sheet-pan chicken with jammy tomatoes",
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "ginger",
             "name": "ginger",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "spinach",
             "name": "spinach",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast",
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "garlic",
             "name": "garlic",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "crispy sheet-pan noodles",
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes",
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "yellow-onion",
             "name": "yellow onion",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "fizzy-water",
             "name": "fizzy water",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "kale",
             "name": "kale",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "beer",
             "name": "beer",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "parsley",
             "name": "parsley",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs",
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes",
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "kefir",
             "name": "kefir",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "kimchi",
             "name": "kimchi",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "sour-cream",
             "name": "sour cream",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "potatoes",
             "name": "potatoes",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "broccoli",
             "name": "broccoli",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "asparagus",
             "name": "asparagus",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "dill",
             "name": "dill",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "red-onion",
             "name": "red onion",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "unsalted-butter",
             "name": "unsalted butter",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "chicken breasts with lemon",
-              "oatmeal chocolate chip cookies",
-              "fried eggs for breakfast"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "scallions",
             "name": "scallions",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "mozzarella",
             "name": "mozzarella",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "cucumbers",
             "name": "cucumbers",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "greek-yogurt",
             "name": "greek yogurt",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "cream-cheese",
             "name": "cream cheese",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "sweet-potato",
             "name": "sweet potato",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "sausages",
             "name": "sausages",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "protein"
           },
           {
+            "id": "tofu",
             "name": "tofu",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy tofu with cashews and blistered snap peas",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "protein"
           },
           {
+            "id": "short-grain-brown-rice",
             "name": "short grain brown rice",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "flue flighter chicken stew"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "tahini",
             "name": "tahini",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "hummus"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chicken-stock",
             "name": "chicken stock",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "orzo",
             "name": "orzo",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "pasta",
             "name": "pasta",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "bread",
             "name": "bread",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast",
-              "peanut butter and jelly on toast",
-              "turkey and cheese sandwiches"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "coffee",
             "name": "coffee",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "cumin",
             "name": "cumin",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "coconut-milk-unsweetened",
             "name": "coconut milk (unsweetened)",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "tortilla-chips",
             "name": "tortilla chips",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "ritz-crackers",
             "name": "Ritz crackers",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "black-beans",
             "name": "black beans",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "mustard",
             "name": "mustard",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey and cheese sandwiches"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chips",
             "name": "chips",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "popcorn",
             "name": "popcorn",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "olive-oil",
             "name": "olive oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "sheet-pan chicken with jammy tomatoes",
-              "turkey meatballs",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "honey",
             "name": "honey",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "black-pepper",
             "name": "black pepper",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "sheet-pan chicken with jammy tomatoes"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "apple-cider-vinegar",
             "name": "apple cider vinegar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "pickles",
             "name": "pickles",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "jasmine-rice",
             "name": "jasmine rice",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "rice-vinegar",
             "name": "rice vinegar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "balsamic-vinegar",
             "name": "balsamic vinegar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "vegetable-oil",
             "name": "vegetable oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy tofu with cashews and blistered snap peas",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baking-soda",
             "name": "baking soda",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "mayonnaise",
             "name": "mayonnaise",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey and cheese sandwiches"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "cannellini-beans",
             "name": "cannellini beans",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "whole-wheat-tortillas",
             "name": "whole-wheat tortillas",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "dumplings",
             "name": "dumplings",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           },
           {
+            "id": "edamame",
             "name": "edamame",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           },
           {
+            "id": "ice-cream",
             "name": "ice cream",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           },
           {
+            "id": "old-fashioned-rolled-oats",
             "name": "old fashioned rolled oats",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chocolate-chips",
             "name": "chocolate chips",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baking-powder",
             "name": "baking powder",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baking-soda-2",
             "name": "baking soda",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "salt",
             "name": "salt",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "oatmeal chocolate chip cookies",
-              "crispy sheet-pan noodles",
-              "sheet-pan chicken with jammy tomatoes"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "white-sugar",
             "name": "white sugar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "vanilla-extract",
             "name": "vanilla extract",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "whole-wheat-flour",
             "name": "whole-wheat flour",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "tomatoes",
             "name": "tomatoes",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "basil",
             "name": "basil",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "parmigiana",
             "name": "parmigiana",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "turkey meatballs"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "1-2-1-2",
             "name": "1/2 & 1/2",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "feta",
             "name": "feta",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "instant-ramen-noodles",
             "name": "instant ramen noodles",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "sesame-oil",
             "name": "sesame oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "soy-sauce",
             "name": "soy sauce",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baby-bok-choy",
             "name": "baby bok choy",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "cilantro",
             "name": "cilantro",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "hoisin",
             "name": "hoisin",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "maple-syrup",
             "name": "maple syrup",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "sesame-seeds",
             "name": "sesame seeds",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "ground-turkey",
             "name": "ground turkey",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs"
-            ]
+            "section": "protein"
           },
           {
+            "id": "panko-bread-crumbs",
             "name": "panko bread crumbs",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "garlic-powder",
             "name": "garlic powder",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "skinless-boneless-chicken-thighs",
             "name": "skinless boneless chicken thighs",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes"
-            ]
+            "section": "protein"
           },
           {
+            "id": "carrots",
             "name": "carrots",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "red-pepper-flakes",
             "name": "red pepper flakes",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chicken-broth",
             "name": "chicken broth",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew",
-              "chicken breasts with lemon"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "string-beans",
             "name": "string beans",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "peaches",
             "name": "peaches",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "whipped-cream",
             "name": "whipped cream",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "kiwi-fruit",
             "name": "kiwi fruit",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "marscapone-cheese",
             "name": "marscapone cheese",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "swordfish",
             "name": "swordfish",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "protein"
           },
           {
+            "id": "eggplant",
             "name": "eggplant",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "tomato-puree",
             "name": "tomato puree",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "pine-nuts",
             "name": "pine nuts",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "french-bread",
             "name": "french bread",
-            "section": "pantry",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "cayenne-pepper",
             "name": "cayenne pepper",
-            "section": "pantry",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "pantry"
           }
         ]
         "###);
@@ -1001,738 +2680,489 @@ pub mod test {
         insta::assert_json_snapshot!(g.collection, @r###"
         [
           {
+            "id": "milk",
             "name": "milk",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "lemons",
             "name": "lemons",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "chicken breasts with lemon",
-              "hummus",
-              "sheet-pan chicken with jammy tomatoes",
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "ginger",
             "name": "ginger",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "spinach",
             "name": "spinach",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast",
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "garlic",
             "name": "garlic",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "crispy sheet-pan noodles",
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes",
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "yellow-onion",
             "name": "yellow onion",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "fizzy-water",
             "name": "fizzy water",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "kale",
             "name": "kale",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "beer",
             "name": "beer",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "parsley",
             "name": "parsley",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs",
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes",
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "kefir",
             "name": "kefir",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "kimchi",
             "name": "kimchi",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "sour-cream",
             "name": "sour cream",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "potatoes",
             "name": "potatoes",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "broccoli",
             "name": "broccoli",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "asparagus",
             "name": "asparagus",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "dill",
             "name": "dill",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "red-onion",
             "name": "red onion",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "unsalted-butter",
             "name": "unsalted butter",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "chicken breasts with lemon",
-              "oatmeal chocolate chip cookies",
-              "fried eggs for breakfast"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "scallions",
             "name": "scallions",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "mozzarella",
             "name": "mozzarella",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "cucumbers",
             "name": "cucumbers",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "greek-yogurt",
             "name": "greek yogurt",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "cream-cheese",
             "name": "cream cheese",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "sweet-potato",
             "name": "sweet potato",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "sausages",
             "name": "sausages",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "protein"
           },
           {
+            "id": "tofu",
             "name": "tofu",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy tofu with cashews and blistered snap peas",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "protein"
           },
           {
+            "id": "short-grain-brown-rice",
             "name": "short grain brown rice",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "flue flighter chicken stew"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "tahini",
             "name": "tahini",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "hummus"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chicken-stock",
             "name": "chicken stock",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "orzo",
             "name": "orzo",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "pasta",
             "name": "pasta",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "bread",
             "name": "bread",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast",
-              "peanut butter and jelly on toast",
-              "turkey and cheese sandwiches"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "coffee",
             "name": "coffee",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "cumin",
             "name": "cumin",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "coconut-milk-unsweetened",
             "name": "coconut milk (unsweetened)",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "tortilla-chips",
             "name": "tortilla chips",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "ritz-crackers",
             "name": "Ritz crackers",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "black-beans",
             "name": "black beans",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "mustard",
             "name": "mustard",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey and cheese sandwiches"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chips",
             "name": "chips",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "popcorn",
             "name": "popcorn",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "olive-oil",
             "name": "olive oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "sheet-pan chicken with jammy tomatoes",
-              "turkey meatballs",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "honey",
             "name": "honey",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "black-pepper",
             "name": "black pepper",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "sheet-pan chicken with jammy tomatoes"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "apple-cider-vinegar",
             "name": "apple cider vinegar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "pickles",
             "name": "pickles",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "jasmine-rice",
             "name": "jasmine rice",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "rice-vinegar",
             "name": "rice vinegar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "balsamic-vinegar",
             "name": "balsamic vinegar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "vegetable-oil",
             "name": "vegetable oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy tofu with cashews and blistered snap peas",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baking-soda",
             "name": "baking soda",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "mayonnaise",
             "name": "mayonnaise",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey and cheese sandwiches"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "cannellini-beans",
             "name": "cannellini beans",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "whole-wheat-tortillas",
             "name": "whole-wheat tortillas",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "dumplings",
             "name": "dumplings",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           },
           {
+            "id": "edamame",
             "name": "edamame",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           },
           {
+            "id": "ice-cream",
             "name": "ice cream",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           },
           {
+            "id": "old-fashioned-rolled-oats",
             "name": "old fashioned rolled oats",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chocolate-chips",
             "name": "chocolate chips",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baking-powder",
             "name": "baking powder",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baking-soda-2",
             "name": "baking soda",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "salt",
             "name": "salt",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "oatmeal chocolate chip cookies",
-              "crispy sheet-pan noodles",
-              "sheet-pan chicken with jammy tomatoes"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "white-sugar",
             "name": "white sugar",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "vanilla-extract",
             "name": "vanilla extract",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "whole-wheat-flour",
             "name": "whole-wheat flour",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "tomatoes",
             "name": "tomatoes",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "basil",
             "name": "basil",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "parmigiana",
             "name": "parmigiana",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "turkey meatballs"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "1-2-1-2",
             "name": "1/2 & 1/2",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "feta",
             "name": "feta",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "fried eggs for breakfast"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "instant-ramen-noodles",
             "name": "instant ramen noodles",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "sesame-oil",
             "name": "sesame oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "soy-sauce",
             "name": "soy sauce",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "baby-bok-choy",
             "name": "baby bok choy",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "cilantro",
             "name": "cilantro",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "hoisin",
             "name": "hoisin",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "maple-syrup",
             "name": "maple syrup",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "sesame-seeds",
             "name": "sesame seeds",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy sheet-pan noodles"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "ground-turkey",
             "name": "ground turkey",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs"
-            ]
+            "section": "protein"
           },
           {
+            "id": "panko-bread-crumbs",
             "name": "panko bread crumbs",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "garlic-powder",
             "name": "garlic powder",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "turkey meatballs"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "skinless-boneless-chicken-thighs",
             "name": "skinless boneless chicken thighs",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes"
-            ]
+            "section": "protein"
           },
           {
+            "id": "carrots",
             "name": "carrots",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "red-pepper-flakes",
             "name": "red pepper flakes",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew",
-              "crispy tofu with cashews and blistered snap peas"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "chicken-broth",
             "name": "chicken broth",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "flue flighter chicken stew",
-              "chicken breasts with lemon"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "string-beans",
             "name": "string beans",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "peaches",
             "name": "peaches",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "whipped-cream",
             "name": "whipped cream",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "kiwi-fruit",
             "name": "kiwi fruit",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           },
           {
+            "id": "marscapone-cheese",
             "name": "marscapone cheese",
-            "section": "dairy",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "dairy"
           },
           {
+            "id": "swordfish",
             "name": "swordfish",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "protein"
           },
           {
+            "id": "eggplant",
             "name": "eggplant",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "tomato-puree",
             "name": "tomato puree",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "pine-nuts",
             "name": "pine nuts",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "french-bread",
             "name": "french bread",
-            "section": "pantry",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "pantry"
           },
           {
+            "id": "cayenne-pepper",
             "name": "cayenne pepper",
-            "section": "pantry",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "pantry"
           }
         ]
         "###);
@@ -1746,6 +3176,7 @@ pub mod test {
 
         insta::assert_json_snapshot!(g, @r###"
         {
+          "schema_version": 4,
           "sections": [
             "fresh",
             "pantry",
@@ -1755,784 +3186,917 @@ pub mod test {
           ],
           "collection": [
             {
+              "id": "eggs",
               "name": "eggs",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies",
-                "fried eggs for breakfast",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "milk",
               "name": "milk",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "lemons",
               "name": "lemons",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "chicken breasts with lemon",
-                "hummus",
-                "sheet-pan chicken with jammy tomatoes",
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "ginger",
               "name": "ginger",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "spinach",
               "name": "spinach",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast",
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "garlic",
               "name": "garlic",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "crispy sheet-pan noodles",
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes",
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "yellow-onion",
               "name": "yellow onion",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "fizzy-water",
               "name": "fizzy water",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "kale",
               "name": "kale",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "beer",
               "name": "beer",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "parsley",
               "name": "parsley",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs",
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes",
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "kefir",
               "name": "kefir",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "kimchi",
               "name": "kimchi",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "sour-cream",
               "name": "sour cream",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "potatoes",
               "name": "potatoes",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "broccoli",
               "name": "broccoli",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "asparagus",
               "name": "asparagus",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "dill",
               "name": "dill",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "red-onion",
               "name": "red onion",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "unsalted-butter",
               "name": "unsalted butter",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "chicken breasts with lemon",
-                "oatmeal chocolate chip cookies",
-                "fried eggs for breakfast"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "scallions",
               "name": "scallions",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "mozzarella",
               "name": "mozzarella",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "cucumbers",
               "name": "cucumbers",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "greek-yogurt",
               "name": "greek yogurt",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "cream-cheese",
               "name": "cream cheese",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "sweet-potato",
               "name": "sweet potato",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "sausages",
               "name": "sausages",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "protein"
             },
             {
+              "id": "tofu",
               "name": "tofu",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy tofu with cashews and blistered snap peas",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "protein"
             },
             {
+              "id": "short-grain-brown-rice",
               "name": "short grain brown rice",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "flue flighter chicken stew"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "tahini",
               "name": "tahini",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "hummus"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chicken-stock",
               "name": "chicken stock",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "orzo",
               "name": "orzo",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "pasta",
               "name": "pasta",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "bread",
               "name": "bread",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast",
-                "peanut butter and jelly on toast",
-                "turkey and cheese sandwiches"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "coffee",
               "name": "coffee",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "cumin",
               "name": "cumin",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "coconut-milk-unsweetened",
               "name": "coconut milk (unsweetened)",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "tortilla-chips",
               "name": "tortilla chips",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "ritz-crackers",
               "name": "Ritz crackers",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "black-beans",
               "name": "black beans",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "mustard",
               "name": "mustard",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey and cheese sandwiches"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chips",
               "name": "chips",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "popcorn",
               "name": "popcorn",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "olive-oil",
               "name": "olive oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "sheet-pan chicken with jammy tomatoes",
-                "turkey meatballs",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "honey",
               "name": "honey",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "black-pepper",
               "name": "black pepper",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "sheet-pan chicken with jammy tomatoes"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "apple-cider-vinegar",
               "name": "apple cider vinegar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "pickles",
               "name": "pickles",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "jasmine-rice",
               "name": "jasmine rice",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "rice-vinegar",
               "name": "rice vinegar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "balsamic-vinegar",
               "name": "balsamic vinegar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "vegetable-oil",
               "name": "vegetable oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy tofu with cashews and blistered snap peas",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baking-soda",
               "name": "baking soda",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "mayonnaise",
               "name": "mayonnaise",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey and cheese sandwiches"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "cannellini-beans",
               "name": "cannellini beans",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "whole-wheat-tortillas",
               "name": "whole-wheat tortillas",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "dumplings",
               "name": "dumplings",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             },
             {
+              "id": "edamame",
               "name": "edamame",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             },
             {
+              "id": "ice-cream",
               "name": "ice cream",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             },
             {
+              "id": "old-fashioned-rolled-oats",
               "name": "old fashioned rolled oats",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chocolate-chips",
               "name": "chocolate chips",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baking-powder",
               "name": "baking powder",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baking-soda-2",
               "name": "baking soda",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "salt",
               "name": "salt",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "oatmeal chocolate chip cookies",
-                "crispy sheet-pan noodles",
-                "sheet-pan chicken with jammy tomatoes"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "white-sugar",
               "name": "white sugar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "vanilla-extract",
               "name": "vanilla extract",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "whole-wheat-flour",
               "name": "whole-wheat flour",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "tomatoes",
               "name": "tomatoes",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "basil",
               "name": "basil",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "parmigiana",
               "name": "parmigiana",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "1-2-1-2",
               "name": "1/2 & 1/2",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "feta",
               "name": "feta",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "instant-ramen-noodles",
               "name": "instant ramen noodles",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "sesame-oil",
               "name": "sesame oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "soy-sauce",
               "name": "soy sauce",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baby-bok-choy",
               "name": "baby bok choy",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "cilantro",
               "name": "cilantro",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "hoisin",
               "name": "hoisin",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "maple-syrup",
               "name": "maple syrup",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "sesame-seeds",
               "name": "sesame seeds",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "ground-turkey",
               "name": "ground turkey",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs"
-              ]
+              "section": "protein"
             },
             {
+              "id": "panko-bread-crumbs",
               "name": "panko bread crumbs",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "garlic-powder",
               "name": "garlic powder",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "skinless-boneless-chicken-thighs",
               "name": "skinless boneless chicken thighs",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes"
-              ]
+              "section": "protein"
             },
             {
+              "id": "carrots",
               "name": "carrots",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "red-pepper-flakes",
               "name": "red pepper flakes",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew",
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chicken-broth",
               "name": "chicken broth",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew",
-                "chicken breasts with lemon"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "string-beans",
               "name": "string beans",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "peaches",
               "name": "peaches",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "whipped-cream",
               "name": "whipped cream",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "kiwi-fruit",
               "name": "kiwi fruit",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "marscapone-cheese",
               "name": "marscapone cheese",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "swordfish",
               "name": "swordfish",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "protein"
             },
             {
+              "id": "eggplant",
               "name": "eggplant",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "tomato-puree",
               "name": "tomato puree",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "pine-nuts",
               "name": "pine nuts",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "french-bread",
               "name": "french bread",
-              "section": "pantry",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "cayenne-pepper",
               "name": "cayenne pepper",
-              "section": "pantry",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "pantry"
             }
           ],
           "recipes": [
-            "oatmeal chocolate chip cookies",
-            "tomato pasta",
-            "fried eggs for breakfast",
-            "crispy sheet-pan noodles",
-            "turkey meatballs",
-            "flue flighter chicken stew",
-            "sheet-pan chicken with jammy tomatoes",
-            "turkey and cheese sandwiches",
-            "peanut butter and jelly on toast",
-            "cheese and apple snack",
-            "hummus",
-            "chicken breasts with lemon",
-            "crispy tofu with cashews and blistered snap peas",
-            "swordfish pasta"
+            {
+              "id": "oatmeal-chocolate-chip-cookies",
+              "name": "oatmeal chocolate chip cookies",
+              "ingredients": [
+                {
+                  "item": "eggs"
+                },
+                {
+                  "item": "unsalted-butter"
+                },
+                {
+                  "item": "old-fashioned-rolled-oats"
+                },
+                {
+                  "item": "chocolate-chips"
+                },
+                {
+                  "item": "baking-powder"
+                },
+                {
+                  "item": "baking-soda-2"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "white-sugar"
+                },
+                {
+                  "item": "vanilla-extract"
+                },
+                {
+                  "item": "whole-wheat-flour"
+                }
+              ]
+            },
+            {
+              "id": "tomato-pasta",
+              "name": "tomato pasta",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "pasta"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "tomatoes"
+                },
+                {
+                  "item": "basil"
+                },
+                {
+                  "item": "parmigiana"
+                }
+              ]
+            },
+            {
+              "id": "fried-eggs-for-breakfast",
+              "name": "fried eggs for breakfast",
+              "ingredients": [
+                {
+                  "item": "eggs"
+                },
+                {
+                  "item": "spinach"
+                },
+                {
+                  "item": "unsalted-butter"
+                },
+                {
+                  "item": "bread"
+                },
+                {
+                  "item": "1-2-1-2"
+                },
+                {
+                  "item": "feta"
+                }
+              ]
+            },
+            {
+              "id": "crispy-sheet-pan-noodles",
+              "name": "crispy sheet-pan noodles",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "tofu"
+                },
+                {
+                  "item": "vegetable-oil"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "instant-ramen-noodles"
+                },
+                {
+                  "item": "sesame-oil"
+                },
+                {
+                  "item": "soy-sauce"
+                },
+                {
+                  "item": "baby-bok-choy"
+                },
+                {
+                  "item": "cilantro"
+                },
+                {
+                  "item": "hoisin"
+                },
+                {
+                  "item": "maple-syrup"
+                },
+                {
+                  "item": "sesame-seeds"
+                }
+              ]
+            },
+            {
+              "id": "turkey-meatballs",
+              "name": "turkey meatballs",
+              "ingredients": [
+                {
+                  "item": "eggs"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "parmigiana"
+                },
+                {
+                  "item": "ground-turkey"
+                },
+                {
+                  "item": "panko-bread-crumbs"
+                },
+                {
+                  "item": "garlic-powder"
+                }
+              ]
+            },
+            {
+              "id": "flue-flighter-chicken-stew",
+              "name": "flue flighter chicken stew",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "spinach"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "yellow-onion"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "short-grain-brown-rice"
+                },
+                {
+                  "item": "skinless-boneless-chicken-thighs"
+                },
+                {
+                  "item": "carrots"
+                },
+                {
+                  "item": "red-pepper-flakes"
+                },
+                {
+                  "item": "chicken-broth"
+                }
+              ]
+            },
+            {
+              "id": "sheet-pan-chicken-with-jammy-tomatoes",
+              "name": "sheet-pan chicken with jammy tomatoes",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "black-pepper"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "skinless-boneless-chicken-thighs"
+                }
+              ]
+            },
+            {
+              "id": "turkey-and-cheese-sandwiches",
+              "name": "turkey and cheese sandwiches",
+              "ingredients": [
+                {
+                  "item": "bread"
+                },
+                {
+                  "item": "mustard"
+                },
+                {
+                  "item": "mayonnaise"
+                }
+              ]
+            },
+            {
+              "id": "peanut-butter-and-jelly-on-toast",
+              "name": "peanut butter and jelly on toast",
+              "ingredients": [
+                {
+                  "item": "bread"
+                }
+              ]
+            },
+            {
+              "id": "cheese-and-apple-snack",
+              "name": "cheese and apple snack"
+            },
+            {
+              "id": "hummus",
+              "name": "hummus",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "tahini"
+                },
+                {
+                  "item": "olive-oil"
+                }
+              ]
+            },
+            {
+              "id": "chicken-breasts-with-lemon",
+              "name": "chicken breasts with lemon",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "unsalted-butter"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "chicken-broth"
+                }
+              ]
+            },
+            {
+              "id": "crispy-tofu-with-cashews-and-blistered-snap-peas",
+              "name": "crispy tofu with cashews and blistered snap peas",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "scallions"
+                },
+                {
+                  "item": "tofu"
+                },
+                {
+                  "item": "coconut-milk-unsweetened"
+                },
+                {
+                  "item": "honey"
+                },
+                {
+                  "item": "rice-vinegar"
+                },
+                {
+                  "item": "vegetable-oil"
+                },
+                {
+                  "item": "soy-sauce"
+                },
+                {
+                  "item": "red-pepper-flakes"
+                }
+              ]
+            },
+            {
+              "id": "swordfish-pasta",
+              "name": "swordfish pasta",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "pasta"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "swordfish"
+                },
+                {
+                  "item": "eggplant"
+                },
+                {
+                  "item": "tomato-puree"
+                },
+                {
+                  "item": "pine-nuts"
+                }
+              ]
+            },
+            {
+              "id": "sheet-pan-salmon-with-broccoli",
+              "name": "Sheet Pan Salmon with Broccoli",
+              "ingredients": [
+                {
+                  "item": "ginger"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "broccoli"
+                },
+                {
+                  "item": "scallions"
+                },
+                {
+                  "item": "short-grain-brown-rice"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "honey"
+                },
+                {
+                  "item": "black-pepper"
+                },
+                {
+                  "item": "rice-vinegar"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "sesame-oil"
+                },
+                {
+                  "item": "soy-sauce"
+                },
+                {
+                  "item": "sesame-seeds"
+                }
+              ]
+            }
           ]
         }
         "###);
 
         let item = GroceriesItem {
+            id: ItemId("cumquats".to_string()),
             name: crate::GroceriesItemName("cumquats".to_string()),
             section: crate::GroceriesItemSection("fresh".to_string()),
-            is_recipe_ingredient: true,
-            recipes: vec![Recipe("cumquat chutney".to_string())],
+            aliases: vec![],
+            on_hand: None,
+            check_first: false,
         };
         let recipe = "cumquat chutney";
 
         let ingredients = "kumquats, carrots, dried apricots, dried cranberries, chili, onion, garlic, cider vinegar, granulated sugar, honey, kosher salt, cardamom, cloves, coriander, ginger, black peppercorns";
 
         g.add_item(item);
-        g.add_recipe(recipe, ingredients)?;
+        // nothing is added while some of the ingredients aren't in the library
+        assert!(matches!(
+            g.add_recipe(recipe, ingredients),
+            Err(ReadError::IngredientsNotInLibrary { items })
+                if items.starts_with("kumquats, dried apricots, dried cranberries")
+        ));
+        assert!(g.recipe(recipe).is_err());
+        g.add_recipe(recipe, "carrots, garlic, honey, ginger")?;
 
         insta::assert_json_snapshot!(g, @r###"
         {
+          "schema_version": 4,
           "sections": [
             "fresh",
             "pantry",
@@ -2542,778 +4106,911 @@ pub mod test {
           ],
           "collection": [
             {
+              "id": "eggs",
               "name": "eggs",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies",
-                "fried eggs for breakfast",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "milk",
               "name": "milk",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "lemons",
               "name": "lemons",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "chicken breasts with lemon",
-                "hummus",
-                "sheet-pan chicken with jammy tomatoes",
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "ginger",
               "name": "ginger",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "cumquat chutney"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "spinach",
               "name": "spinach",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast",
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "garlic",
               "name": "garlic",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "crispy sheet-pan noodles",
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes",
-                "swordfish pasta",
-                "cumquat chutney"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "yellow-onion",
               "name": "yellow onion",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "fizzy-water",
               "name": "fizzy water",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "kale",
               "name": "kale",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "beer",
               "name": "beer",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "parsley",
               "name": "parsley",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs",
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes",
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "kefir",
               "name": "kefir",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "kimchi",
               "name": "kimchi",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "sour-cream",
               "name": "sour cream",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "potatoes",
               "name": "potatoes",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "broccoli",
               "name": "broccoli",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "asparagus",
               "name": "asparagus",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "dill",
               "name": "dill",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "red-onion",
               "name": "red onion",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "unsalted-butter",
               "name": "unsalted butter",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "chicken breasts with lemon",
-                "oatmeal chocolate chip cookies",
-                "fried eggs for breakfast"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "scallions",
               "name": "scallions",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "mozzarella",
               "name": "mozzarella",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "cucumbers",
               "name": "cucumbers",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "greek-yogurt",
               "name": "greek yogurt",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "cream-cheese",
               "name": "cream cheese",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "sweet-potato",
               "name": "sweet potato",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "sausages",
               "name": "sausages",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "protein"
             },
             {
+              "id": "tofu",
               "name": "tofu",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy tofu with cashews and blistered snap peas",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "protein"
             },
             {
+              "id": "short-grain-brown-rice",
               "name": "short grain brown rice",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "flue flighter chicken stew"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "tahini",
               "name": "tahini",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "hummus"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chicken-stock",
               "name": "chicken stock",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "orzo",
               "name": "orzo",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "pasta",
               "name": "pasta",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "bread",
               "name": "bread",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast",
-                "peanut butter and jelly on toast",
-                "turkey and cheese sandwiches"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "coffee",
               "name": "coffee",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "cumin",
               "name": "cumin",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "coconut-milk-unsweetened",
               "name": "coconut milk (unsweetened)",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "tortilla-chips",
               "name": "tortilla chips",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "ritz-crackers",
               "name": "Ritz crackers",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "black-beans",
               "name": "black beans",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "mustard",
               "name": "mustard",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey and cheese sandwiches"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chips",
               "name": "chips",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "popcorn",
               "name": "popcorn",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "olive-oil",
               "name": "olive oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "sheet-pan chicken with jammy tomatoes",
-                "turkey meatballs",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "honey",
               "name": "honey",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "cumquat chutney"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "black-pepper",
               "name": "black pepper",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "sheet-pan chicken with jammy tomatoes"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "apple-cider-vinegar",
               "name": "apple cider vinegar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "pickles",
               "name": "pickles",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "jasmine-rice",
               "name": "jasmine rice",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "rice-vinegar",
               "name": "rice vinegar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "balsamic-vinegar",
               "name": "balsamic vinegar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "vegetable-oil",
               "name": "vegetable oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy tofu with cashews and blistered snap peas",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baking-soda",
               "name": "baking soda",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "mayonnaise",
               "name": "mayonnaise",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey and cheese sandwiches"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "cannellini-beans",
               "name": "cannellini beans",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "whole-wheat-tortillas",
               "name": "whole-wheat tortillas",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "dumplings",
               "name": "dumplings",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             },
             {
+              "id": "edamame",
               "name": "edamame",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             },
             {
+              "id": "ice-cream",
               "name": "ice cream",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             },
             {
+              "id": "old-fashioned-rolled-oats",
               "name": "old fashioned rolled oats",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chocolate-chips",
               "name": "chocolate chips",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baking-powder",
               "name": "baking powder",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baking-soda-2",
               "name": "baking soda",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "salt",
               "name": "salt",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "oatmeal chocolate chip cookies",
-                "crispy sheet-pan noodles",
-                "sheet-pan chicken with jammy tomatoes"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "white-sugar",
               "name": "white sugar",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "vanilla-extract",
               "name": "vanilla extract",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "whole-wheat-flour",
               "name": "whole-wheat flour",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "tomatoes",
               "name": "tomatoes",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "basil",
               "name": "basil",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "parmigiana",
               "name": "parmigiana",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "1-2-1-2",
               "name": "1/2 & 1/2",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "feta",
               "name": "feta",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "fried eggs for breakfast"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "instant-ramen-noodles",
               "name": "instant ramen noodles",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "sesame-oil",
               "name": "sesame oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "soy-sauce",
               "name": "soy sauce",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "baby-bok-choy",
               "name": "baby bok choy",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "cilantro",
               "name": "cilantro",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "hoisin",
               "name": "hoisin",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "maple-syrup",
               "name": "maple syrup",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "sesame-seeds",
               "name": "sesame seeds",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy sheet-pan noodles"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "ground-turkey",
               "name": "ground turkey",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs"
-              ]
+              "section": "protein"
             },
             {
+              "id": "panko-bread-crumbs",
               "name": "panko bread crumbs",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "garlic-powder",
               "name": "garlic powder",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "turkey meatballs"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "skinless-boneless-chicken-thighs",
               "name": "skinless boneless chicken thighs",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes"
-              ]
+              "section": "protein"
             },
             {
+              "id": "carrots",
               "name": "carrots",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew",
-                "cumquat chutney"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "red-pepper-flakes",
               "name": "red pepper flakes",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew",
-                "crispy tofu with cashews and blistered snap peas"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "chicken-broth",
               "name": "chicken broth",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "flue flighter chicken stew",
-                "chicken breasts with lemon"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "string-beans",
               "name": "string beans",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "peaches",
               "name": "peaches",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "whipped-cream",
               "name": "whipped cream",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "kiwi-fruit",
               "name": "kiwi fruit",
-              "section": "fresh",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "fresh"
             },
             {
+              "id": "marscapone-cheese",
               "name": "marscapone cheese",
-              "section": "dairy",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "dairy"
             },
             {
+              "id": "swordfish",
               "name": "swordfish",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "protein"
             },
             {
+              "id": "eggplant",
               "name": "eggplant",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "tomato-puree",
               "name": "tomato puree",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "pine-nuts",
               "name": "pine nuts",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "french-bread",
               "name": "french bread",
-              "section": "pantry",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "cayenne-pepper",
               "name": "cayenne pepper",
-              "section": "pantry",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "pantry"
             },
             {
+              "id": "cumquats",
               "name": "cumquats",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "cumquat chutney"
-              ]
+              "section": "fresh"
             }
           ],
           "recipes": [
-            "oatmeal chocolate chip cookies",
-            "tomato pasta",
-            "fried eggs for breakfast",
-            "crispy sheet-pan noodles",
-            "turkey meatballs",
-            "flue flighter chicken stew",
-            "sheet-pan chicken with jammy tomatoes",
-            "turkey and cheese sandwiches",
-            "peanut butter and jelly on toast",
-            "cheese and apple snack",
-            "hummus",
-            "chicken breasts with lemon",
-            "crispy tofu with cashews and blistered snap peas",
-            "swordfish pasta",
-            "cumquat chutney"
+            {
+              "id": "oatmeal-chocolate-chip-cookies",
+              "name": "oatmeal chocolate chip cookies",
+              "ingredients": [
+                {
+                  "item": "eggs"
+                },
+                {
+                  "item": "unsalted-butter"
+                },
+                {
+                  "item": "old-fashioned-rolled-oats"
+                },
+                {
+                  "item": "chocolate-chips"
+                },
+                {
+                  "item": "baking-powder"
+                },
+                {
+                  "item": "baking-soda-2"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "white-sugar"
+                },
+                {
+                  "item": "vanilla-extract"
+                },
+                {
+                  "item": "whole-wheat-flour"
+                }
+              ]
+            },
+            {
+              "id": "tomato-pasta",
+              "name": "tomato pasta",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "pasta"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "tomatoes"
+                },
+                {
+                  "item": "basil"
+                },
+                {
+                  "item": "parmigiana"
+                }
+              ]
+            },
+            {
+              "id": "fried-eggs-for-breakfast",
+              "name": "fried eggs for breakfast",
+              "ingredients": [
+                {
+                  "item": "eggs"
+                },
+                {
+                  "item": "spinach"
+                },
+                {
+                  "item": "unsalted-butter"
+                },
+                {
+                  "item": "bread"
+                },
+                {
+                  "item": "1-2-1-2"
+                },
+                {
+                  "item": "feta"
+                }
+              ]
+            },
+            {
+              "id": "crispy-sheet-pan-noodles",
+              "name": "crispy sheet-pan noodles",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "tofu"
+                },
+                {
+                  "item": "vegetable-oil"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "instant-ramen-noodles"
+                },
+                {
+                  "item": "sesame-oil"
+                },
+                {
+                  "item": "soy-sauce"
+                },
+                {
+                  "item": "baby-bok-choy"
+                },
+                {
+                  "item": "cilantro"
+                },
+                {
+                  "item": "hoisin"
+                },
+                {
+                  "item": "maple-syrup"
+                },
+                {
+                  "item": "sesame-seeds"
+                }
+              ]
+            },
+            {
+              "id": "turkey-meatballs",
+              "name": "turkey meatballs",
+              "ingredients": [
+                {
+                  "item": "eggs"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "parmigiana"
+                },
+                {
+                  "item": "ground-turkey"
+                },
+                {
+                  "item": "panko-bread-crumbs"
+                },
+                {
+                  "item": "garlic-powder"
+                }
+              ]
+            },
+            {
+              "id": "flue-flighter-chicken-stew",
+              "name": "flue flighter chicken stew",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "spinach"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "yellow-onion"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "short-grain-brown-rice"
+                },
+                {
+                  "item": "skinless-boneless-chicken-thighs"
+                },
+                {
+                  "item": "carrots"
+                },
+                {
+                  "item": "red-pepper-flakes"
+                },
+                {
+                  "item": "chicken-broth"
+                }
+              ]
+            },
+            {
+              "id": "sheet-pan-chicken-with-jammy-tomatoes",
+              "name": "sheet-pan chicken with jammy tomatoes",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "black-pepper"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "skinless-boneless-chicken-thighs"
+                }
+              ]
+            },
+            {
+              "id": "turkey-and-cheese-sandwiches",
+              "name": "turkey and cheese sandwiches",
+              "ingredients": [
+                {
+                  "item": "bread"
+                },
+                {
+                  "item": "mustard"
+                },
+                {
+                  "item": "mayonnaise"
+                }
+              ]
+            },
+            {
+              "id": "peanut-butter-and-jelly-on-toast",
+              "name": "peanut butter and jelly on toast",
+              "ingredients": [
+                {
+                  "item": "bread"
+                }
+              ]
+            },
+            {
+              "id": "cheese-and-apple-snack",
+              "name": "cheese and apple snack"
+            },
+            {
+              "id": "hummus",
+              "name": "hummus",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "tahini"
+                },
+                {
+                  "item": "olive-oil"
+                }
+              ]
+            },
+            {
+              "id": "chicken-breasts-with-lemon",
+              "name": "chicken breasts with lemon",
+              "ingredients": [
+                {
+                  "item": "lemons"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "unsalted-butter"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "chicken-broth"
+                }
+              ]
+            },
+            {
+              "id": "crispy-tofu-with-cashews-and-blistered-snap-peas",
+              "name": "crispy tofu with cashews and blistered snap peas",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "scallions"
+                },
+                {
+                  "item": "tofu"
+                },
+                {
+                  "item": "coconut-milk-unsweetened"
+                },
+                {
+                  "item": "honey"
+                },
+                {
+                  "item": "rice-vinegar"
+                },
+                {
+                  "item": "vegetable-oil"
+                },
+                {
+                  "item": "soy-sauce"
+                },
+                {
+                  "item": "red-pepper-flakes"
+                }
+              ]
+            },
+            {
+              "id": "swordfish-pasta",
+              "name": "swordfish pasta",
+              "ingredients": [
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "parsley"
+                },
+                {
+                  "item": "pasta"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "swordfish"
+                },
+                {
+                  "item": "eggplant"
+                },
+                {
+                  "item": "tomato-puree"
+                },
+                {
+                  "item": "pine-nuts"
+                }
+              ]
+            },
+            {
+              "id": "sheet-pan-salmon-with-broccoli",
+              "name": "Sheet Pan Salmon with Broccoli",
+              "ingredients": [
+                {
+                  "item": "ginger"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "broccoli"
+                },
+                {
+                  "item": "scallions"
+                },
+                {
+                  "item": "short-grain-brown-rice"
+                },
+                {
+                  "item": "olive-oil"
+                },
+                {
+                  "item": "honey"
+                },
+                {
+                  "item": "black-pepper"
+                },
+                {
+                  "item": "rice-vinegar"
+                },
+                {
+                  "item": "salt"
+                },
+                {
+                  "item": "sesame-oil"
+                },
+                {
+                  "item": "soy-sauce"
+                },
+                {
+                  "item": "sesame-seeds"
+                }
+              ]
+            },
+            {
+              "id": "cumquat-chutney",
+              "name": "cumquat chutney",
+              "ingredients": [
+                {
+                  "item": "carrots"
+                },
+                {
+                  "item": "garlic"
+                },
+                {
+                  "item": "honey"
+                },
+                {
+                  "item": "ginger"
+                }
+              ]
+            }
           ]
         }
         "###);
@@ -1,16 +1,25 @@
-use crate::Recipe;
+use crate::{convert, new_id, Amount, ReadError};
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::ops::Deref;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct GroceriesItem {
+    pub id: ItemId,                    // e.g. "apples", kept when renamed
     pub name: GroceriesItemName,       // e.g. "apples"
     pub section: GroceriesItemSection, // e.g. "fresh"
-    pub is_recipe_ingredient: bool,    // i.e. true
-    pub recipes: Vec<Recipe>,          // list of recipes: "apple pie", "cheese plate", ...
-                                       //pub on_list: bool,
-                                       //pub on_checklist: bool,
+    //pub on_list: bool,
+    //pub on_checklist: bool,
+    /// Other names recipes and searches know it by, e.g. "green onions"
+    /// for scallions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// How much of this item we have at home, without a quantity if we
+    /// have some but don't know how much
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_hand: Option<Amount>,
+    /// Whether to check at home before shopping for it, e.g. for spices
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub check_first: bool,
 }
 
 impl GroceriesItem {
@@ -25,17 +34,90 @@ impl GroceriesItem {
         //let name = name_and_section.get(0).expect("no grocery name found!");
         //let section = name_and_section.get(1).expect("no grocery section found");
         GroceriesItem {
+            id: ItemId(new_id(&name.0, |_| false)),
             name,
             section,
-            is_recipe_ingredient: false,
-            recipes: vec![],
+            aliases: vec![],
+            on_hand: None,
+            check_first: false,
             //on_list: false,
             //on_checklist: false,
         }
     }
 
+    /// Adds `amount` to what we have at home, or notes that we have some.
+    pub fn restock(&mut self, amount: Option<&Amount>) -> Result<(), ReadError> {
+        let amount = match amount.filter(|amount| amount.quantity.is_some()) {
+            Some(amount) => amount,
+            None => {
+                self.on_hand.get_or_insert_with(Amount::default);
+                return Ok(());
+            }
+        };
+        match &mut self.on_hand {
+            Some(Amount {
+                quantity: Some(quantity),
+                unit,
+                ..
+            }) => {
+                let more = amount.quantity.unwrap_or_default();
+                *quantity += convert(more, amount.unit.as_deref(), unit.as_deref())
+                    .ok_or(ReadError::UnitMismatch)?;
+            }
+            _ => {
+                self.on_hand = Some(Amount {
+                    preparation: None,
+                    ..amount.clone()
+                })
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes `amount` away from what we have at home, or all of it.
+    pub fn use_up(&mut self, amount: Option<&Amount>) -> Result<(), ReadError> {
+        let on_hand = self.on_hand.as_mut().ok_or(ReadError::NotInPantry)?;
+        let (amount, quantity) = match (amount, on_hand.quantity.as_mut()) {
+            (Some(amount), Some(quantity)) => (amount, quantity),
+            // we can't tell how much is left of an amount we don't know
+            (Some(_), None) => return Ok(()),
+            (None, _) => {
+                self.on_hand = None;
+                return Ok(());
+            }
+        };
+        let used = amount.quantity.unwrap_or_default();
+        *quantity -= convert(used, amount.unit.as_deref(), on_hand.unit.as_deref())
+            .ok_or(ReadError::UnitMismatch)?;
+        if *quantity < 0.01 {
+            self.on_hand = None;
+        }
+        Ok(())
+    }
+
+    /// The item's name followed by its aliases.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.0.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+
+    /// Whether `name` is one of the item's aliases, whatever its case.
+    pub fn has_alias(&self, name: &str) -> bool {
+        self.aliases
+            .iter()
+            .any(|alias| alias.to_lowercase() == name.trim().to_lowercase())
+    }
+
+    /// How alike `s` is to the item's name or the closest of its aliases.
+    pub fn similarity(&self, s: &str) -> f64 {
+        self.names()
+            .map(|name| crate::similarity(s, name))
+            .fold(0.0, f64::max)
+    }
+
+    /// Whether `s` is close enough to this item's name, or one of its
+    /// aliases, to be it, typos and all.
     pub fn matches(&self, s: &str) -> bool {
-        s.split(' ').all(|word| !self.name.0.contains(word))
+        self.similarity(s) >= crate::MATCH_THRESHOLD
     }
 }
 
@@ -45,11 +127,14 @@ impl fmt::Display for GroceriesItem {
     }
 }
 
-impl Deref for GroceriesItem {
-    type Target = Vec<Recipe>;
+/// What recipes, lists and other records refer to an item by, so they
+/// still find it after it's renamed.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemId(pub String);
 
-    fn deref(&self) -> &Self::Target {
-        &self.recipes
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
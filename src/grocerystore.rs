@@ -0,0 +1,141 @@
+use crate::{GroceriesItem, GroceriesItemSection, ItemId};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A section, or a single item, that a store names. Items go by their id,
+/// so the store still finds them after they are renamed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StoreEntry {
+    Section(GroceriesItemSection),
+    Item(ItemId),
+}
+
+impl StoreEntry {
+    /// Whether `item` is this item, or in this section.
+    pub fn covers(&self, item: &GroceriesItem) -> bool {
+        match self {
+            StoreEntry::Section(section) => *section == item.section,
+            StoreEntry::Item(id) => *id == item.id,
+        }
+    }
+}
+
+/// A place we shop, with the order we walk past things there and what it sells.
+///
+/// Both `aisles` and `carries` name sections or single items. An item named
+/// in `aisles` goes where it is named rather than with the rest of its section.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct GroceryStore {
+    pub name: String,
+    /// Sections and items in walking order, anything not named comes last
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aisles: Vec<StoreEntry>,
+    /// Sections and items the store stocks, or everything if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub carries: Vec<StoreEntry>,
+}
+
+impl GroceryStore {
+    pub fn new(name: &str) -> Self {
+        GroceryStore {
+            name: name.to_string(),
+            ..GroceryStore::default()
+        }
+    }
+
+    pub fn carries(&self, item: &GroceriesItem) -> bool {
+        self.carries.is_empty() || self.carries.iter().any(|entry| entry.covers(item))
+    }
+
+    /// Where `item` comes in the walk through the store, after everything
+    /// named in `aisles` if it isn't.
+    pub fn aisle(&self, item: &GroceriesItem) -> usize {
+        let position = |entry: StoreEntry| self.aisles.iter().position(|aisle| *aisle == entry);
+        position(StoreEntry::Item(item.id.clone()))
+            .or_else(|| position(StoreEntry::Section(item.section.clone())))
+            .unwrap_or(self.aisles.len())
+    }
+
+    /// The items this store carries, in walking order. Items in the same
+    /// aisle keep the order they were given in.
+    pub fn walk<'a, I>(&self, items: I) -> Vec<&'a GroceriesItem>
+    where
+        I: IntoIterator<Item = &'a GroceriesItem>,
+    {
+        let mut items: Vec<_> = items
+            .into_iter()
+            .filter(|item| self.carries(item))
+            .collect();
+        items.sort_by_key(|item| self.aisle(item));
+        items
+    }
+
+    /// Names `new` wherever the store names `old`, after a section was
+    /// renamed or an item merged into another.
+    pub fn replace(&mut self, old: &StoreEntry, new: &StoreEntry) {
+        for entry in self.aisles.iter_mut().chain(self.carries.iter_mut()) {
+            if entry == old {
+                *entry = new.clone();
+            }
+        }
+    }
+
+    /// Forgets a section or item that is no longer in the library.
+    pub fn forget(&mut self, old: &StoreEntry) {
+        self.aisles.retain(|entry| entry != old);
+        self.carries.retain(|entry| entry != old);
+    }
+}
+
+impl fmt::Display for GroceryStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_walk() {
+        let items = vec![
+            GroceriesItem::new("garlic", "fresh"),
+            GroceriesItem::new("basil", "fresh"),
+            GroceriesItem::new("milk", "dairy"),
+            GroceriesItem::new("rice", "dry goods"),
+            GroceriesItem::new("olive oil", "dry goods"),
+        ];
+        let names = |store: &GroceryStore| {
+            store
+                .walk(&items)
+                .iter()
+                .map(|item| item.name.0.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        // a store with no layout keeps the list's order
+        assert_eq!(
+            names(&GroceryStore::new("corner shop")),
+            ["garlic", "basil", "milk", "rice", "olive oil"]
+        );
+
+        let section = |name: &str| StoreEntry::Section(GroceriesItemSection(name.to_string()));
+        let olive_oil = StoreEntry::Item(items[4].id.clone());
+        let mut store = GroceryStore::new("supermarket");
+        store.aisles = vec![section("dairy"), olive_oil.clone(), section("fresh")];
+        assert_eq!(
+            names(&store),
+            ["milk", "olive oil", "garlic", "basil", "rice"]
+        );
+
+        store.carries = vec![section("fresh"), olive_oil.clone()];
+        assert_eq!(names(&store), ["olive oil", "garlic", "basil"]);
+
+        store.replace(&section("fresh"), &section("produce"));
+        store.forget(&olive_oil);
+        assert_eq!(store.aisles, [section("dairy"), section("produce")]);
+        assert_eq!(store.carries, [section("produce")]);
+    }
+}
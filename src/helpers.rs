@@ -28,6 +28,72 @@ pub fn get_user_input() -> Result<String, ReadError> {
     Ok(output)
 }
 
+/// An id for something called `name` that stays put when it's renamed,
+/// e.g. "olive-oil", numbered if `taken` says that one is in use already.
+pub fn new_id<F: Fn(&str) -> bool>(name: &str, taken: F) -> String {
+    let mut slug = String::new();
+    for c in name.trim().to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = match slug.trim_end_matches('-') {
+        "" => "id".to_string(),
+        slug => slug.to_string(),
+    };
+    let mut id = slug.clone();
+    let mut n = 1;
+    while taken(&id) {
+        n += 1;
+        id = format!("{}-{}", slug, n);
+    }
+    id
+}
+
 pub fn write<P: AsRef<Path>>(path: P, object: String) -> Result<(), ReadError> {
-    Ok(fs::write(path, &object)?)
+    write_bytes(path, object.as_bytes())
+}
+
+/// Writes to a temporary file next to `path`, syncs it to disk and renames it
+/// into place, so `path` holds either the old or the new contents, never half.
+pub fn write_bytes<P: AsRef<Path>>(path: P, bytes: &[u8]) -> Result<(), ReadError> {
+    let path = path.as_ref();
+    let file_name = path.file_name().ok_or(ReadError::ParseInputError)?;
+    let tmp = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+    create_parent(path)?;
+
+    let written = File::create(&tmp).and_then(|mut file| {
+        file.write_all(bytes)?;
+        file.sync_all()
+    });
+    if let Err(e) = written.and_then(|_| fs::rename(&tmp, path)) {
+        let _ = fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    sync_parent(path)
+}
+
+/// Makes sure the directory `path` goes in exists.
+pub fn create_parent<P: AsRef<Path>>(path: P) -> Result<(), ReadError> {
+    match path.as_ref().parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => Ok(fs::create_dir_all(parent)?),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(unix)]
+fn sync_parent(path: &Path) -> Result<(), ReadError> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    File::open(parent)?.sync_all()?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn sync_parent(_path: &Path) -> Result<(), ReadError> {
+    Ok(())
 }
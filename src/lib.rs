@@ -2,28 +2,60 @@
 // Note: Loads the contents of the module from another file
 //       with the same name as the module. Read more at
 //       https://doc.rust-lang.org/book/ch07-05-separating-modules-into-different-files.html
+mod amount;
+mod backup;
 mod cli;
+mod config;
+mod doctor;
 mod errors;
 mod groceries;
 mod groceriesitem;
+mod grocerystore;
 mod helpers;
+mod migrations;
 mod recipes;
+mod run_doctor;
 mod run_groceries;
+mod run_migrate;
+mod run_pantry;
 mod run_recipes;
+mod run_restore;
+mod run_sections;
 mod run_shopping_list;
+mod run_stores;
+mod search;
+mod sectionguess;
 mod shoppinglist;
 pub mod startup;
+mod store;
 
 // Note: Re-exports the content of the square_content module to keep paths short.
 //       Read more at https://doc.rust-lang.org/reference/items/use-declarations.html#use-visibility
+pub use crate::amount::*;
+pub use crate::backup::*;
 pub use crate::cli::*;
+pub use crate::config::*;
+pub use crate::doctor::*;
 pub use crate::errors::*;
+// groceries and shoppinglist both have a `pub mod test`
+#[cfg_attr(test, allow(ambiguous_glob_reexports))]
 pub use crate::groceries::*;
 pub use crate::groceriesitem::*;
+pub use crate::grocerystore::*;
 pub use crate::helpers::*;
+pub use crate::migrations::*;
 pub use crate::recipes::*;
+// each run module has its own `run`, reached through its module, e.g.
+// `grusterylist::startup::run()`
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use crate::run_groceries::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use crate::run_recipes::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use crate::run_shopping_list::*;
+pub use crate::search::*;
+pub use crate::sectionguess::*;
 pub use crate::shoppinglist::*;
+#[allow(ambiguous_glob_reexports, unused_imports)]
 pub use crate::startup::*;
+pub use crate::store::*;
@@ -0,0 +1,523 @@
+use crate::{new_id, Groceries, ReadError};
+use serde_json::Value;
+
+/// Version of the groceries library format written by this build.
+///
+/// Only changes older builds couldn't read, or would lose data reading,
+/// bump it. New fields that default when missing don't.
+pub const GROCERIES_SCHEMA_VERSION: u64 = 4;
+
+/// Version of the shopping list format written by this build.
+pub const LIST_SCHEMA_VERSION: u64 = 3;
+
+/// Upgrades a document from one schema version to the next,
+/// returning a description of each change it made. Shopping lists are
+/// given the library, if there is one, so they can use the ids it has.
+type Migration = fn(&mut Value, Option<&Groceries>) -> Vec<String>;
+
+// `GROCERIES_MIGRATIONS[n]` upgrades a library from version `n` to `n + 1`
+const GROCERIES_MIGRATIONS: [Migration; GROCERIES_SCHEMA_VERSION as usize] = [
+    recipes_to_records,
+    add_ids,
+    recipes_own_ingredients,
+    store_item_ids,
+];
+
+// `LIST_MIGRATIONS[n]` upgrades a shopping list from version `n` to `n + 1`
+const LIST_MIGRATIONS: [Migration; LIST_SCHEMA_VERSION as usize] =
+    [add_list_ids, recipes_own_ingredients, recipes_to_ids];
+
+/// Brings a groceries library up to [`GROCERIES_SCHEMA_VERSION`].
+pub fn migrate_groceries(document: &mut Value) -> Result<Vec<String>, ReadError> {
+    migrate(document, &GROCERIES_MIGRATIONS, None)
+}
+
+/// Brings a shopping list up to [`LIST_SCHEMA_VERSION`], referring to items
+/// and recipes by the ids `library` gave them.
+pub fn migrate_list(
+    document: &mut Value,
+    library: Option<&Groceries>,
+) -> Result<Vec<String>, ReadError> {
+    migrate(document, &LIST_MIGRATIONS, library)
+}
+
+pub fn schema_version(document: &Value) -> u64 {
+    // files written before versioning have no `schema_version`
+    document
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0)
+}
+
+fn migrate(
+    document: &mut Value,
+    migrations: &[Migration],
+    library: Option<&Groceries>,
+) -> Result<Vec<String>, ReadError> {
+    if !document.is_object() {
+        return Err(ReadError::ParseInputError);
+    }
+    let version = schema_version(document);
+    if version > migrations.len() as u64 {
+        return Err(ReadError::SchemaVersionError { version });
+    }
+
+    let mut changes = vec![];
+    for (from, migration) in migrations.iter().enumerate().skip(version as usize) {
+        let to = from as u64 + 1;
+        changes.push(format!("schema version {} -> {}", from, to));
+        changes.extend(
+            migration(document, library)
+                .into_iter()
+                .map(|c| format!("  {}", c)),
+        );
+        document["schema_version"] = Value::from(to);
+    }
+    Ok(changes)
+}
+
+// recipes were just their names, now they are records with a `name` and details
+fn recipes_to_records(document: &mut Value, _: Option<&Groceries>) -> Vec<String> {
+    let mut changes = vec![];
+    if let Some(recipes) = document.get_mut("recipes").and_then(Value::as_array_mut) {
+        for recipe in recipes.iter_mut() {
+            if let Value::String(name) = recipe {
+                changes.push(format!("turns recipe `{}` into a record", name));
+                *recipe = serde_json::json!({ "name": name });
+            }
+        }
+    }
+    changes
+}
+
+// items and recipes get an `id` that stays put when they are renamed, and items
+// refer to their recipes by it. Recipes items named that the library didn't
+// list are added to it, so their names aren't lost.
+fn add_ids(document: &mut Value, _: Option<&Groceries>) -> Vec<String> {
+    let mut changes = vec!["adds item and recipe `id`s".to_string()];
+    let mut recipes: Vec<(String, String)> = vec![];
+    if let Some(records) = document.get_mut("recipes").and_then(Value::as_array_mut) {
+        for recipe in records.iter_mut() {
+            let name = recipe["name"].as_str().unwrap_or_default().to_string();
+            let id = new_id(&name, |id| recipes.iter().any(|(_, taken)| taken == id));
+            recipe["id"] = Value::from(id.as_str());
+            recipes.push((name, id));
+        }
+    }
+    let mut missing = vec![];
+    let mut items: Vec<String> = vec![];
+    if let Some(collection) = document.get_mut("collection").and_then(Value::as_array_mut) {
+        for item in collection.iter_mut() {
+            let name = item["name"].as_str().unwrap_or_default();
+            let id = new_id(name, |id| items.iter().any(|taken| taken == id));
+            item["id"] = Value::from(id.as_str());
+            items.push(id);
+            let mut recipe_id = |name: &str| match recipes.iter().find(|(n, _)| n == name) {
+                Some((_, id)) => id.clone(),
+                None => {
+                    let id = new_id(name, |id| recipes.iter().any(|(_, taken)| taken == id));
+                    recipes.push((name.to_string(), id.clone()));
+                    missing.push((name.to_string(), id.clone()));
+                    id
+                }
+            };
+            if let Some(names) = item.get_mut("recipes").and_then(Value::as_array_mut) {
+                for recipe in names.iter_mut() {
+                    if let Value::String(name) = recipe {
+                        *recipe = Value::from(recipe_id(name));
+                    }
+                }
+            }
+            if let Some(amounts) = item.get_mut("amounts").and_then(Value::as_object_mut) {
+                *amounts = std::mem::take(amounts)
+                    .into_iter()
+                    .map(|(name, amount)| (recipe_id(&name), amount))
+                    .collect();
+            }
+        }
+    }
+    if let Some(records) = document.get_mut("recipes").and_then(Value::as_array_mut) {
+        for (name, id) in missing {
+            changes.push(format!(
+                "adds recipe `{}`, which items used but the library didn't list",
+                name
+            ));
+            records.push(serde_json::json!({ "id": id, "name": name }));
+        }
+    }
+    changes
+}
+
+// the list refers to items and recipes by `id` too, the ones the library gave
+// them. Names can't be slugged again, as a case-duplicate like "Olive Oil"
+// got `olive-oil-2` there. A name the library doesn't have gets an id none
+// of its own take, so it can't point at something else.
+fn add_list_ids(document: &mut Value, library: Option<&Groceries>) -> Vec<String> {
+    let item_id = |name: &str| {
+        let collection = library
+            .map(|library| &library.collection[..])
+            .unwrap_or_default();
+        match collection.iter().find(|item| item.name.0 == name) {
+            Some(item) => item.id.0.clone(),
+            None => new_id(name, |id| collection.iter().any(|item| item.id.0 == id)),
+        }
+    };
+    let recipe_id = |name: &str| {
+        let recipes = library
+            .map(|library| &library.recipes[..])
+            .unwrap_or_default();
+        match recipes.iter().find(|recipe| recipe.name.0 == name) {
+            Some(recipe) => recipe.id.0.clone(),
+            None => new_id(name, |id| recipes.iter().any(|recipe| recipe.id.0 == id)),
+        }
+    };
+    let mut changes = vec!["adds item and recipe `id`s".to_string()];
+    for field in ["checklist", "groceries"] {
+        if let Some(items) = document.get_mut(field).and_then(Value::as_array_mut) {
+            for item in items.iter_mut() {
+                item["id"] = Value::from(item_id(item["name"].as_str().unwrap_or_default()));
+                if let Some(recipes) = item.get_mut("recipes").and_then(Value::as_array_mut) {
+                    for recipe in recipes.iter_mut() {
+                        *recipe = Value::from(recipe_id(recipe.as_str().unwrap_or_default()));
+                    }
+                }
+                if let Some(amounts) = item.get_mut("amounts").and_then(Value::as_object_mut) {
+                    *amounts = std::mem::take(amounts)
+                        .into_iter()
+                        .map(|(name, amount)| (recipe_id(&name), amount))
+                        .collect();
+                }
+            }
+        }
+    }
+    if let Some(recipes) = document.get_mut("recipes").and_then(Value::as_array_mut) {
+        for recipe in recipes.iter_mut() {
+            if let Value::String(name) = recipe {
+                changes.push(format!("turns recipe `{}` into a record", name));
+                *recipe = serde_json::json!({ "id": recipe_id(name), "name": name });
+            }
+        }
+    }
+    if let Some(servings) = document.get_mut("servings").and_then(Value::as_object_mut) {
+        *servings = std::mem::take(servings)
+            .into_iter()
+            .map(|(name, servings)| (recipe_id(&name), servings))
+            .collect();
+    }
+    if let Some(purchased) = document.get_mut("purchased").and_then(Value::as_array_mut) {
+        for item in purchased.iter_mut() {
+            *item = Value::from(item_id(item.as_str().unwrap_or_default()));
+        }
+    }
+    changes
+}
+
+// recipes list their `ingredients`, with the amounts items kept for them, and
+// items no longer say which recipes use them. Which order the ingredients
+// were given in was never kept, so they come in the order of the items. Works
+// the same on the list's copies of items and recipes, until the list stops
+// keeping copies of recipes below.
+fn recipes_own_ingredients(document: &mut Value, _: Option<&Groceries>) -> Vec<String> {
+    let mut ingredients: Vec<(String, Value)> = vec![];
+    for field in ["collection", "checklist", "groceries"] {
+        if let Some(items) = document.get_mut(field).and_then(Value::as_array_mut) {
+            for item in items.iter_mut().filter_map(Value::as_object_mut) {
+                let id = item.get("id").cloned().unwrap_or_default();
+                let amounts = item.remove("amounts").unwrap_or_default();
+                item.remove("is_recipe_ingredient");
+                if let Some(Value::Array(recipes)) = item.remove("recipes") {
+                    for recipe in recipes.iter().filter_map(Value::as_str) {
+                        let mut ingredient = serde_json::json!({ "item": id });
+                        if let Some(amount) = amounts.get(recipe) {
+                            ingredient["amount"] = amount.clone();
+                        }
+                        ingredients.push((recipe.to_string(), ingredient));
+                    }
+                }
+            }
+        }
+    }
+    if let Some(recipes) = document.get_mut("recipes").and_then(Value::as_array_mut) {
+        for recipe in recipes.iter_mut().filter_map(Value::as_object_mut) {
+            let id = recipe
+                .get("id")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            let mut list: Vec<Value> = vec![];
+            for (_, ingredient) in ingredients.iter().filter(|(recipe, _)| *recipe == id) {
+                // an item can be on both the list and its checklist
+                if !list.iter().any(|x| x["item"] == ingredient["item"]) {
+                    list.push(ingredient.clone());
+                }
+            }
+            if !list.is_empty() {
+                recipe.insert("ingredients".to_string(), Value::from(list));
+            }
+        }
+    }
+    vec!["moves recipe ingredients from items to recipe `ingredients`".to_string()]
+}
+
+// the list keeps the ids of its recipes rather than copies of them, and takes
+// their ingredients from the library, so changes made to a recipe there
+// reach lists it's already on
+fn recipes_to_ids(document: &mut Value, _: Option<&Groceries>) -> Vec<String> {
+    if let Some(recipes) = document.get_mut("recipes").and_then(Value::as_array_mut) {
+        for recipe in recipes.iter_mut() {
+            if let Some(id) = recipe.get("id").cloned() {
+                *recipe = id;
+            }
+        }
+    }
+    vec!["keeps recipe `id`s in place of copies of the recipes".to_string()]
+}
+
+// stores name items by `id`, like recipes do, and say whether they name a
+// section or an item. A name that is both is taken for the section, and one
+// that is neither is kept as a section for `doctor` to find.
+fn store_item_ids(document: &mut Value, _: Option<&Groceries>) -> Vec<String> {
+    let sections = document["sections"].as_array().cloned().unwrap_or_default();
+    let items: Vec<(Value, Value)> = document["collection"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| (item["name"].clone(), item["id"].clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(stores) = document.get_mut("stores").and_then(Value::as_array_mut) {
+        for store in stores.iter_mut() {
+            for field in ["aisles", "carries"] {
+                if let Some(entries) = store.get_mut(field).and_then(Value::as_array_mut) {
+                    for entry in entries.iter_mut().filter(|entry| entry.is_string()) {
+                        *entry = match items.iter().find(|(name, _)| name == entry) {
+                            Some((_, id)) if !sections.contains(entry) => {
+                                serde_json::json!({ "item": id })
+                            }
+                            _ => serde_json::json!({ "section": entry }),
+                        };
+                    }
+                }
+            }
+        }
+    }
+    vec!["stores name items by `id`".to_string()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_migrate_unversioned() -> Result<(), Box<dyn std::error::Error>> {
+        let mut document: Value =
+            serde_json::from_str(r#"{"sections":[],"collection":[],"recipes":["hummus"]}"#)?;
+        let changes = migrate_groceries(&mut document)?;
+        insta::assert_json_snapshot!(changes, @r###"
+        [
+          "schema version 0 -> 1",
+          "  turns recipe `hummus` into a record",
+          "schema version 1 -> 2",
+          "  adds item and recipe `id`s",
+          "schema version 2 -> 3",
+          "  moves recipe ingredients from items to recipe `ingredients`",
+          "schema version 3 -> 4",
+          "  stores name items by `id`"
+        ]
+        "###);
+        insta::assert_json_snapshot!(document["recipes"], @r###"
+        [
+          {
+            "id": "hummus",
+            "name": "hummus"
+          }
+        ]
+        "###);
+        assert_eq!(schema_version(&document), GROCERIES_SCHEMA_VERSION);
+
+        // already up to date
+        assert!(migrate_groceries(&mut document)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_ids() -> Result<(), Box<dyn std::error::Error>> {
+        let mut library: Value = serde_json::from_str(
+            r#"{"schema_version":1,"sections":["pantry"],"recipes":[{"name":"Hummus"}],
+            "collection":[
+                {"name":"olive oil","section":"pantry","is_recipe_ingredient":true,
+                 "recipes":["Hummus","falafel"],"amounts":{"Hummus":{"quantity":2.0,"unit":"tbsp"}}},
+                {"name":"Olive Oil","section":"pantry","is_recipe_ingredient":false,"recipes":[]}
+            ]}"#,
+        )?;
+        let changes = migrate_groceries(&mut library)?;
+        insta::assert_json_snapshot!(changes, @r###"
+        [
+          "schema version 1 -> 2",
+          "  adds item and recipe `id`s",
+          "  adds recipe `falafel`, which items used but the library didn't list",
+          "schema version 2 -> 3",
+          "  moves recipe ingredients from items to recipe `ingredients`",
+          "schema version 3 -> 4",
+          "  stores name items by `id`"
+        ]
+        "###);
+        let library = crate::Groceries::from_document(library)?;
+        insta::assert_json_snapshot!(library, @r###"
+        {
+          "schema_version": 4,
+          "sections": [
+            "pantry"
+          ],
+          "collection": [
+            {
+              "id": "olive-oil",
+              "name": "olive oil",
+              "section": "pantry"
+            },
+            {
+              "id": "olive-oil-2",
+              "name": "Olive Oil",
+              "section": "pantry"
+            }
+          ],
+          "recipes": [
+            {
+              "id": "hummus",
+              "name": "Hummus",
+              "ingredients": [
+                {
+                  "item": "olive-oil",
+                  "amount": {
+                    "quantity": 2.0,
+                    "unit": "tbsp"
+                  }
+                }
+              ]
+            },
+            {
+              "id": "falafel",
+              "name": "falafel",
+              "ingredients": [
+                {
+                  "item": "olive-oil"
+                }
+              ]
+            }
+          ]
+        }
+        "###);
+
+        // "Olive Oil" is `olive-oil-2` in the library, and sumac isn't in it
+        let mut list: Value = serde_json::from_str(
+            r#"{"checklist":[{"name":"sumac","section":"pantry","is_recipe_ingredient":false,"recipes":[]}],
+            "recipes":["Hummus"],
+            "groceries":[{"name":"olive oil","section":"pantry","is_recipe_ingredient":true,
+                "recipes":["Hummus"],"amounts":{"Hummus":{"quantity":2.0,"unit":"tbsp"}}},
+                {"name":"Olive Oil","section":"pantry","is_recipe_ingredient":false,"recipes":[]}],
+            "servings":{"Hummus":{"wanted":8,"makes":4}},"purchased":["Olive Oil"]}"#,
+        )?;
+        migrate_list(&mut list, Some(&library))?;
+        let list = crate::ShoppingList::from_document(list, None)?;
+        insta::assert_json_snapshot!(list, @r###"
+        {
+          "schema_version": 3,
+          "checklist": [
+            {
+              "id": "sumac",
+              "name": "sumac",
+              "section": "pantry"
+            }
+          ],
+          "recipes": [
+            "hummus"
+          ],
+          "groceries": [
+            {
+              "id": "olive-oil",
+              "name": "olive oil",
+              "section": "pantry"
+            },
+            {
+              "id": "olive-oil-2",
+              "name": "Olive Oil",
+              "section": "pantry"
+            }
+          ],
+          "servings": {
+            "hummus": {
+              "wanted": 8,
+              "makes": 4
+            }
+          },
+          "purchased": [
+            "olive-oil-2"
+          ]
+        }
+        "###);
+        // the list's ids are the library's
+        assert_eq!(list.groceries[0].id, library.item("olive oil")?.id);
+        assert_eq!(list.groceries[1].id, library.item("Olive Oil")?.id);
+        assert_eq!(list.recipes[0], library.recipe("Hummus")?.id);
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_item_ids() -> Result<(), Box<dyn std::error::Error>> {
+        let mut library: Value = serde_json::from_str(
+            r#"{"schema_version":3,"sections":["fresh","pantry"],"recipes":[],
+            "collection":[
+                {"id":"olive-oil","name":"olive oil","section":"pantry"},
+                {"id":"olive-oil-2","name":"Olive Oil","section":"pantry"}
+            ],
+            "stores":[{"name":"corner shop","aisles":["Olive Oil","fresh","bakery"],"carries":["olive oil"]}]}"#,
+        )?;
+        migrate_groceries(&mut library)?;
+        let mut library = crate::Groceries::from_document(library)?;
+        insta::assert_json_snapshot!(library.stores, @r###"
+        [
+          {
+            "name": "corner shop",
+            "aisles": [
+              {
+                "item": "olive-oil-2"
+              },
+              {
+                "section": "fresh"
+              },
+              {
+                "section": "bakery"
+              }
+            ],
+            "carries": [
+              {
+                "item": "olive-oil"
+              }
+            ]
+          }
+        ]
+        "###);
+
+        // so they still find an item after it's renamed
+        library.rename_item("Olive Oil", "extra virgin olive oil")?;
+        let store = library.store("corner shop")?;
+        let item = library.item("extra virgin olive oil")?;
+        assert_eq!(store.aisle(item), 0);
+        assert!(!store.carries(item));
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_newer_version() -> Result<(), Box<dyn std::error::Error>> {
+        let mut document: Value = serde_json::from_str(
+            r#"{"schema_version":999,"checklist":[],"recipes":[],"groceries":[]}"#,
+        )?;
+        assert!(matches!(
+            migrate_list(&mut document, None),
+            Err(ReadError::SchemaVersionError { version: 999 })
+        ));
+        Ok(())
+    }
+}
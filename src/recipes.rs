@@ -1,52 +1,232 @@
-use crate::GroceriesItemName;
 use crate::ReadError;
+use crate::{new_id, parse_quantity, parse_unit, Amount};
+use crate::{GroceriesItemName, ItemId};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::Deref;
 use std::str::FromStr;
 
+/// A recipe in the library.
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
-pub struct Recipe(pub String);
+pub struct RecipeRecord {
+    pub id: RecipeId,
+    pub name: RecipeName,
+    /// The items it takes, in the order the recipe lists them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ingredients: Vec<RecipeIngredient>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub servings: Option<u32>,
+    /// In minutes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prep_time: Option<u32>,
+    /// In minutes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cook_time: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instructions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+impl fmt::Display for RecipeRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl RecipeRecord {
+    pub fn new(name: RecipeName) -> Self {
+        RecipeRecord {
+            id: RecipeId(new_id(&name.0, |_| false)),
+            name,
+            ..RecipeRecord::default()
+        }
+    }
+
+    /// Whether `item` is one of the recipe's ingredients.
+    pub fn uses(&self, item: &ItemId) -> bool {
+        self.ingredients
+            .iter()
+            .any(|ingredient| &ingredient.item == item)
+    }
+
+    /// How much of `item` the recipe needs, if the recipe says.
+    pub fn amount(&self, item: &ItemId) -> Option<&Amount> {
+        self.ingredients
+            .iter()
+            .find(|ingredient| &ingredient.item == item)
+            .map(|ingredient| &ingredient.amount)
+            .filter(|amount| !amount.is_empty())
+    }
+
+    /// Uses `with` wherever the recipe uses `item`. If it uses both, `with`
+    /// keeps its place and takes `item`'s amount if it has none of its own.
+    pub fn replace_ingredient(&mut self, item: &ItemId, with: &ItemId) {
+        let Some(i) = self.ingredients.iter().position(|x| &x.item == item) else {
+            return;
+        };
+        match self.ingredients.iter().position(|x| &x.item == with) {
+            Some(j) => {
+                let old = self.ingredients.remove(i);
+                let j = if j > i { j - 1 } else { j };
+                if self.ingredients[j].amount.is_empty() {
+                    self.ingredients[j].amount = old.amount;
+                }
+            }
+            None => self.ingredients[i].item = with.clone(),
+        }
+    }
+
+    /// Inserts a step before step number `at`, counting from 1, or adds it at the end.
+    pub fn add_instruction(&mut self, step: &str, at: Option<usize>) -> Result<(), ReadError> {
+        match at {
+            Some(at) if at == 0 || at > self.instructions.len() + 1 => Err(ReadError::StepNotFound),
+            Some(at) => {
+                self.instructions.insert(at - 1, step.to_string());
+                Ok(())
+            }
+            None => {
+                self.instructions.push(step.to_string());
+                Ok(())
+            }
+        }
+    }
+
+    /// Deletes step number `step`, counting from 1.
+    pub fn delete_instruction(&mut self, step: usize) -> Result<(), ReadError> {
+        if step == 0 || step > self.instructions.len() {
+            return Err(ReadError::StepNotFound);
+        }
+        self.instructions.remove(step - 1);
+        Ok(())
+    }
+}
+
+/// An item a recipe uses, and how much of it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecipeIngredient {
+    pub item: ItemId,
+    #[serde(default, skip_serializing_if = "Amount::is_empty")]
+    pub amount: Amount,
+}
+
+/// What shopping lists refer to a recipe by, so they still find
+/// it after it's renamed.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RecipeId(pub String);
+
+impl fmt::Display for RecipeId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The name of a recipe.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RecipeName(pub String);
 
-impl fmt::Display for Recipe {
+/// What the name of a recipe used to be called, before recipes became
+/// [`RecipeRecord`]s with a name and details.
+#[deprecated(note = "renamed to `RecipeName`; a whole recipe is a `RecipeRecord`")]
+pub type Recipe = RecipeName;
+
+impl fmt::Display for RecipeName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
-impl Recipe {
+impl RecipeName {
     pub fn new(s: String) -> Result<Self, ReadError> {
-        Recipe::from_str(&s)
+        RecipeName::from_str(&s)
     }
 }
 
-impl FromStr for Recipe {
+impl FromStr for RecipeName {
     type Err = ReadError;
 
     fn from_str(s: &str) -> Result<Self, ReadError> {
-        Ok(Recipe(s.to_string()))
+        Ok(RecipeName(s.to_string()))
+    }
+}
+
+/// One entry of a recipe's ingredients, e.g. "2 cups tomatoes; chopped".
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Ingredient {
+    pub name: GroceriesItemName,
+    pub amount: Amount,
+}
+
+impl FromStr for Ingredient {
+    type Err = ReadError;
+
+    /// Reads an optional quantity and unit before the name and an optional
+    /// preparation note after a `;`. Anything that doesn't read as an amount
+    /// is left as part of the name, so "1/2 & 1/2" stays "1/2 & 1/2".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (s, preparation) = match s.split_once(';') {
+            Some((s, preparation)) => (s, Some(preparation.trim().to_string())),
+            None => (s, None),
+        };
+        let words: Vec<&str> = s.split_whitespace().collect();
+
+        let mut amount = Amount {
+            preparation: preparation.filter(|p| !p.is_empty()),
+            ..Amount::default()
+        };
+        let mut rest = &words[..];
+        if let Some(quantity) = words.first().and_then(|w| parse_quantity(w)) {
+            let unit = words.get(1).and_then(|w| parse_unit(w));
+            let skip = match unit {
+                Some(_) if words.get(2) == Some(&"of") => 3,
+                Some(_) => 2,
+                None => 1,
+            };
+            let name_starts_with_letter = words
+                .get(skip)
+                .and_then(|w| w.chars().next())
+                .is_some_and(char::is_alphabetic);
+            if name_starts_with_letter {
+                amount.quantity = Some(quantity);
+                amount.unit = unit.map(String::from);
+                rest = &words[skip..];
+            }
+        }
+
+        Ok(Ingredient {
+            name: GroceriesItemName(rest.join(" ")),
+            amount,
+        })
     }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
-pub struct Ingredients(pub Vec<GroceriesItemName>);
+pub struct Ingredients(pub Vec<Ingredient>);
 
 impl Ingredients {
     fn new() -> Ingredients {
         Ingredients(Vec::new())
     }
 
-    fn add(&mut self, elem: GroceriesItemName) {
+    fn add(&mut self, elem: Ingredient) {
         self.0.push(elem);
     }
 
     pub fn from_input_string(s: &str) -> Result<Self, ReadError> {
         Ingredients::from_str(s)
     }
+
+    pub fn get(&self, name: &GroceriesItemName) -> Option<&Ingredient> {
+        self.0.iter().find(|ingredient| &ingredient.name == name)
+    }
 }
 
-impl FromIterator<GroceriesItemName> for Ingredients {
-    fn from_iter<I: IntoIterator<Item = GroceriesItemName>>(iter: I) -> Self {
+impl FromIterator<Ingredient> for Ingredients {
+    fn from_iter<I: IntoIterator<Item = Ingredient>>(iter: I) -> Self {
         let mut c = Ingredients::new();
 
         for i in iter {
@@ -60,14 +240,20 @@ impl FromStr for Ingredients {
     type Err = crate::errors::ReadError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(s.split(',')
-            .map(|item| GroceriesItemName(item.trim().to_lowercase()))
-            .collect())
+        s.split(',')
+            // nothing between commas, as after a trailing one, isn't an ingredient
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| {
+                let mut ingredient = Ingredient::from_str(item)?;
+                ingredient.name.0 = ingredient.name.0.to_lowercase();
+                Ok(ingredient)
+            })
+            .collect()
     }
 }
 
 impl Deref for Ingredients {
-    type Target = Vec<crate::GroceriesItemName>;
+    type Target = Vec<Ingredient>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
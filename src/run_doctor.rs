@@ -0,0 +1,63 @@
+use crate::{diff, prompt_for_y, ReadError, Store};
+use clap::ArgMatches;
+
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store) -> Result<(), ReadError> {
+    let fix = sync_matches.contains_id("fix");
+
+    if !store.has_groceries()? {
+        eprintln!("There's no groceries library to check");
+        return Ok(());
+    }
+    let mut groceries = store.load_groceries()?;
+    let problems = groceries.diagnose();
+    if problems.is_empty() {
+        eprintln!("The groceries library is in good shape");
+        return Ok(());
+    }
+    eprintln!("Found these problems in the groceries library:");
+    for problem in problems.iter() {
+        eprintln!("\t{}", problem);
+    }
+    if !fix {
+        eprintln!("Run `doctor --fix` to repair them");
+        return Ok(());
+    }
+
+    let before = serde_json::to_string_pretty(&groceries)?;
+    let repaired = groceries.repair();
+    let after = serde_json::to_string_pretty(&groceries)?;
+    eprintln!("Repairing them makes these changes:");
+    for line in diff(&before, &after) {
+        eprintln!("{}", line);
+    }
+
+    // the list has to keep up with items that were merged or given new ids
+    let mut list = None;
+    if store.has_list()? {
+        let mut sl = store.load_list()?;
+        let before = serde_json::to_string_pretty(&sl)?;
+        sl.repair(&repaired, &groceries);
+        let after = serde_json::to_string_pretty(&sl)?;
+        if before != after {
+            eprintln!("And these to the shopping list:");
+            for line in diff(&before, &after) {
+                eprintln!("{}", line);
+            }
+            list = Some(sl);
+        }
+    }
+
+    eprintln!(
+        "Save the repairs?\n\
+        *y*\n\
+        *any other key* to leave things as they are"
+    );
+    if prompt_for_y()? {
+        store.save_groceries(&groceries)?;
+        if let Some(sl) = list {
+            store.save_list(&sl)?;
+        }
+        eprintln!("Repaired the groceries library");
+    }
+    Ok(())
+}
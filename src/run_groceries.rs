@@ -1,17 +1,173 @@
+use crate::joined;
+use crate::prompt_for_y;
+use crate::Config;
 use crate::Groceries;
 use crate::GroceriesItem;
+use crate::ItemId;
 use crate::ReadError;
-use crate::prompt_for_y;
+use crate::Store;
+use clap::ArgMatches;
 
-pub fn run() -> Result<(), ReadError> {
-    Groceries::prompt_view_groceries()?;
-    Groceries::prompt_add_groceries()?;
-    Groceries::prompt_save()?;
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store, config: &Config) -> Result<(), ReadError> {
+    let mut groceries = if store.has_groceries()? {
+        store.load_groceries()?
+    } else {
+        Groceries::new_initialized()?
+    };
+    groceries.fill_sections(&config.sections);
+    match sync_matches.subcommand() {
+        Some(("check-first", s_matches)) => {
+            groceries.set_check_first(&joined(s_matches, "item"), !s_matches.is_present("off"))?
+        }
+        Some(("add", s_matches)) if s_matches.is_present("name") => groceries
+            .add_item_to_section(&joined(s_matches, "name"), &joined(s_matches, "section"))?,
+        Some(("delete", s_matches)) => groceries.delete_item(&joined(s_matches, "name"))?,
+        Some(("rename", s_matches)) => {
+            let (name, new_name) = (joined(s_matches, "name"), joined(s_matches, "new"));
+            let id = groceries.item(&name)?.id.clone();
+            groceries.rename_item(&name, &new_name)?;
+            rename_on_list(store, &id, &new_name)?;
+        }
+        Some(("merge", s_matches)) if s_matches.is_present("keep") => {
+            let keep = s_matches.get_one::<String>("keep").unwrap();
+            let dupes: Vec<&str> = s_matches.values_of("dupes").unwrap().collect();
+            let ids = groceries.merge_items(keep, &dupes)?;
+            merge_on_list(store, groceries.item(keep)?, &ids)?;
+        }
+        Some(("merge", _)) => {
+            for (keep, ids) in groceries.prompt_merge_duplicates()? {
+                let keep = groceries.item_by_id(&keep).ok_or(ReadError::ItemNotFound)?;
+                merge_on_list(store, keep, &ids)?;
+            }
+        }
+        Some(("aliases", s_matches)) => match s_matches.subcommand() {
+            Some(("add", a_matches)) => {
+                groceries.add_alias(&joined(a_matches, "item"), &joined(a_matches, "alias"))?
+            }
+            Some(("remove", a_matches)) => {
+                groceries.remove_alias(&joined(a_matches, "item"), &joined(a_matches, "alias"))?
+            }
+            _ => {
+                let item = s_matches
+                    .values_of("item")
+                    .map(|values| values.collect::<Vec<_>>().join(" "));
+                return groceries.print_aliases(item.as_deref());
+            }
+        },
+        Some(("move", s_matches)) => {
+            groceries.move_item(&joined(s_matches, "name"), &joined(s_matches, "section"))?
+        }
+        Some(("show", s_matches)) => {
+            let section = s_matches
+                .values_of("section")
+                .map(|values| values.collect::<Vec<_>>().join(" "));
+            return groceries.print_groceries(section.as_deref());
+        }
+        Some(("search", s_matches)) => {
+            return groceries.print_search(&joined(s_matches, "query"));
+        }
+        _ => {
+            if config.prompts.view_library {
+                groceries.prompt_view_groceries()?;
+            }
+            groceries.prompt_add_groceries()?;
+        }
+    }
+    groceries.prompt_save(store)?;
+    Ok(())
+}
+
+// the saved list, the one at `--list-path`, follows items renamed in the library
+fn rename_on_list(store: &dyn Store, id: &ItemId, new_name: &str) -> Result<(), ReadError> {
+    if store.has_list()? {
+        let mut sl = store.load_list()?;
+        sl.rename_item(id, new_name);
+        store.save_list(&sl)?;
+    }
+    Ok(())
+}
+
+// the saved list follows items merged in the library
+fn merge_on_list(
+    store: &dyn Store,
+    keep: &GroceriesItem,
+    dupes: &[ItemId],
+) -> Result<(), ReadError> {
+    if store.has_list()? {
+        let mut sl = store.load_list()?;
+        sl.merge_items(keep, dupes);
+        store.save_list(&sl)?;
+    }
     Ok(())
 }
 
 impl Groceries {
-    pub(crate) fn prompt_view_groceries() -> Result<(), ReadError> {
+    /// Goes through the items that look like the same thing twice, merging
+    /// those we say are into the one we pick. Returns the id of each item
+    /// kept with the ids of those merged into it.
+    pub(crate) fn prompt_merge_duplicates(
+        &mut self,
+    ) -> Result<Vec<(ItemId, Vec<ItemId>)>, ReadError> {
+        let duplicates: Vec<Vec<ItemId>> = self
+            .duplicates()
+            .iter()
+            .map(|items| items.iter().map(|item| item.id.clone()).collect())
+            .collect();
+        if duplicates.is_empty() {
+            eprintln!("Nothing in the library looks like a duplicate");
+        }
+        let mut merged = vec![];
+        for ids in duplicates {
+            eprintln!("Are any of these the same item?");
+            for (i, id) in ids.iter().enumerate() {
+                let item = self.item_by_id(id).ok_or(ReadError::ItemNotFound)?;
+                let recipes: Vec<&str> = self
+                    .used_in(id)
+                    .map(|recipe| recipe.name.0.as_str())
+                    .collect();
+                if recipes.is_empty() {
+                    eprintln!("*{}* {} ({})", i + 1, item, item.section);
+                } else {
+                    let recipes = recipes.join(", ");
+                    eprintln!("*{}* {} ({}; {})", i + 1, item, item.section, recipes);
+                }
+            }
+            eprintln!(
+                "*a number* to keep that item and merge the others into it\n\
+                *numbers*, e.g. 1 3, to keep the first and merge the rest into it\n\
+                *any other key* to leave them as they are"
+            );
+            let input = crate::get_user_input()?;
+            let picked: Vec<&ItemId> = input
+                .split_whitespace()
+                .map_while(|n| n.parse::<usize>().ok())
+                .filter_map(|n| n.checked_sub(1).and_then(|i| ids.get(i)))
+                .collect();
+            let (keep, dupes) = match picked.as_slice() {
+                [] => continue,
+                [keep] => (*keep, ids.iter().collect::<Vec<_>>()),
+                [keep, rest @ ..] => (*keep, rest.to_vec()),
+            };
+            let dupes: Vec<ItemId> = dupes
+                .into_iter()
+                .filter(|id| *id != keep)
+                .cloned()
+                .collect();
+            if dupes.is_empty() {
+                continue;
+            }
+            for dupe in dupes.iter() {
+                let position = |id: &ItemId| self.collection.iter().position(|item| &item.id == id);
+                if let (Some(from), Some(into)) = (position(dupe), position(keep)) {
+                    self.merge_item(from, into);
+                }
+            }
+            merged.push((keep.clone(), dupes));
+        }
+        Ok(merged)
+    }
+
+    pub(crate) fn prompt_view_groceries(&self) -> Result<(), ReadError> {
         eprintln!(
             "View the groceries in our library?\n\
              --y\n\
@@ -19,27 +175,77 @@ impl Groceries {
         );
 
         while crate::prompt_for_y()? {
-            let path = "groceries.json";
+            eprintln!();
+            for item in self.items() {
+                eprintln!("{}", item);
+            }
+            eprintln!();
+            eprintln!();
+            eprintln!(
+                "View the groceries in our library?\n\
+                    --y\n\
+                    --any other key to continue"
+            );
+        }
+        Ok(())
+    }
 
-            #[allow(irrefutable_let_patterns)]
-            if let groceries = Groceries::from_path(path)? {
-                eprintln!();
-                for item in groceries.items() {
-                    eprintln!("{}", item);
+    /// Prints the items in `section`, or in every section, with the recipes that use them.
+    pub(crate) fn print_groceries(&self, section: Option<&str>) -> Result<(), ReadError> {
+        if let Some(section) = section {
+            if !self.sections.iter().any(|s| s.0 == section) {
+                return Err(ReadError::SectionNotFound);
+            }
+        }
+        for sec in self
+            .sections
+            .iter()
+            .filter(|s| section.is_none_or(|section| s.0 == section))
+        {
+            println!("{}:", sec);
+            for item in self.collection.iter().filter(|item| &item.section == sec) {
+                let recipes: Vec<&str> = self
+                    .used_in(&item.id)
+                    .map(|recipe| recipe.name.0.as_str())
+                    .collect();
+                if recipes.is_empty() {
+                    println!("\t{}", item);
+                } else {
+                    println!("\t{} ({})", item, recipes.join(", "));
                 }
-                eprintln!();
-                eprintln!();
-                eprintln!(
-                    "View the groceries in our library?\n\
-                        --y\n\
-                        --any other key to continue"
-                );
             }
         }
         Ok(())
     }
 
-    pub(crate) fn prompt_add_groceries() -> Result<(), ReadError> {
+    /// Prints the aliases of `item`, or of every item that has some.
+    pub(crate) fn print_aliases(&self, item: Option<&str>) -> Result<(), ReadError> {
+        let items: Vec<&GroceriesItem> = match item {
+            Some(name) => vec![self.item(name)?],
+            None => self
+                .items()
+                .filter(|item| !item.aliases.is_empty())
+                .collect(),
+        };
+        for item in items {
+            println!("{}: {}", item, item.aliases.join(", "));
+        }
+        Ok(())
+    }
+
+    /// Prints the items named like `query`, best match first.
+    pub(crate) fn print_search(&self, query: &str) -> Result<(), ReadError> {
+        let mut found = self.get_item_matches(query).peekable();
+        if found.peek().is_none() {
+            return Err(ReadError::ItemNotFound);
+        }
+        for item in found {
+            println!("{} ({})", item, item.section);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn prompt_add_groceries(&mut self) -> Result<(), ReadError> {
         eprintln!(
             "Add groceries to our library?\n\
             --y\n\
@@ -47,7 +253,7 @@ impl Groceries {
         );
 
         while crate::prompt_for_y()? {
-            Groceries::add_grocery_item()?;
+            self.add_grocery_item()?;
 
             eprintln!(
                 "Add more groceries to our library?\n\
@@ -58,55 +264,39 @@ impl Groceries {
         Ok(())
     }
 
-    fn add_grocery_item() -> Result<(), ReadError> {
+    fn add_grocery_item(&mut self) -> Result<(), ReadError> {
         eprintln!(
             "Enter the item\n\
             e.g. 'bread'"
         );
         let name = crate::get_user_input()?;
-        eprintln!(
-            "Enter the section (fresh, pantry, protein, dairy, freezer)\n\
-            e.g. 'fresh'"
-        );
-        let section = crate::get_user_input()?;
-
-        let mut groceries = if Groceries::from_path("groceries.json").is_err() {
-            Groceries::new_initialized()?
-        } else {
-            Groceries::from_path("groceries.json")?
-        };
+        let section = self.prompt_for_section(&name)?;
 
         let mut present = false;
-        for item in groceries.get_item_matches(&name) {
-                eprintln!(
-                    "is *{}* a match?\n\
+        for item in self.get_item_matches(&name) {
+            eprintln!(
+                "is *{}* a match?\n\
                 *y* for yes
                 *any other key* for no",
-                    item
-                );
-                if prompt_for_y()? {
-                    present = true;
-                    break;
-                }
-            }   
-        
+                item
+            );
+            if prompt_for_y()? {
+                present = true;
+                break;
+            }
+        }
+
         if present {
             eprintln!("Item already in library");
         } else {
-            let new_item = GroceriesItem::new(&name, &section);
-            groceries.add_item(new_item);
+            let new_item = GroceriesItem::new(&name, &section.0);
+            self.add_item(new_item);
         }
         Ok(())
     }
 
-    pub(crate) fn prompt_save() -> Result<(), ReadError> {
-        let path = "groceries.json";
-        let groceries = if Groceries::from_path(path).is_err() {
-            Groceries::new_initialized()?
-        } else {
-            Groceries::from_path(path)?
-        };
-        groceries.save(path)?;
+    pub(crate) fn prompt_save(&self, store: &dyn Store) -> Result<(), ReadError> {
+        store.save_groceries(self)?;
         Ok(())
     }
 }
@@ -0,0 +1,44 @@
+use crate::{migrate_groceries, migrate_list, Groceries, ReadError, ShoppingList, Store};
+use clap::ArgMatches;
+
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store) -> Result<(), ReadError> {
+    let check = sync_matches.contains_id("check");
+
+    // the list takes its ids from the migrated library
+    let mut library = None;
+    if store.has_groceries()? {
+        let mut document = store.load_groceries_document()?;
+        let changes = migrate_groceries(&mut document)?;
+        report("groceries library", &changes, check);
+        let groceries = Groceries::from_document(document)?;
+        if !check && !changes.is_empty() {
+            store.save_groceries(&groceries)?;
+        }
+        library = Some(groceries);
+    }
+
+    if store.has_list()? {
+        let mut document = store.load_list_document()?;
+        let changes = migrate_list(&mut document, library.as_ref())?;
+        report("shopping list", &changes, check);
+        if !check && !changes.is_empty() {
+            store.save_list(&ShoppingList::from_document(document, library.as_ref())?)?;
+        }
+    }
+    Ok(())
+}
+
+fn report(name: &str, changes: &[String], check: bool) {
+    if changes.is_empty() {
+        eprintln!("The {} is up to date", name);
+        return;
+    }
+    if check {
+        eprintln!("Migrating the {} would make these changes:", name);
+    } else {
+        eprintln!("Migrated the {}:", name);
+    }
+    for change in changes {
+        eprintln!("\t{}", change);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::joined;
+use crate::Amount;
+use crate::Groceries;
+use crate::ReadError;
+use crate::Store;
+use crate::Total;
+use clap::ArgMatches;
+
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store) -> Result<(), ReadError> {
+    if !store.has_groceries()? {
+        return Err(ReadError::LibraryNotFound);
+    }
+    let mut groceries = store.load_groceries()?;
+    match sync_matches.subcommand() {
+        Some(("set", s_matches)) => {
+            groceries.set_stock(&joined(s_matches, "item"), amount(s_matches)?)?
+        }
+        Some(("use", s_matches)) => {
+            groceries.use_stock(&joined(s_matches, "item"), amount(s_matches)?.as_ref())?
+        }
+        Some(("restock", s_matches)) if s_matches.is_present("from list") => {
+            if !store.has_list()? {
+                return Err(ReadError::ListNotFound);
+            }
+            let mut sl = store.load_list()?;
+            let bought: Vec<String> = sl
+                .groceries
+                .iter()
+                .map(|item| item.name.0.clone())
+                .collect();
+            for name in bought {
+                sl.check_off(&name, &mut groceries)?;
+            }
+            store.save_list(&sl)?;
+        }
+        Some(("restock", s_matches)) => {
+            groceries.restock(&joined(s_matches, "item"), amount(s_matches)?.as_ref())?
+        }
+        _ => {
+            print_pantry(&groceries);
+            return Ok(());
+        }
+    }
+    print_pantry(&groceries);
+    store.save_groceries(&groceries)?;
+    Ok(())
+}
+
+fn amount(matches: &ArgMatches) -> Result<Option<Amount>, ReadError> {
+    match matches.values_of("amount") {
+        Some(words) => Ok(Some(words.collect::<Vec<_>>().join(" ").parse()?)),
+        None => Ok(None),
+    }
+}
+
+fn print_pantry(groceries: &Groceries) {
+    eprintln!();
+    eprintln!("Pantry:");
+    for item in groceries.pantry() {
+        // written the way the shopping list writes amounts, e.g. "13 tbsp"
+        let mut on_hand = Total::default();
+        if let Some(amount) = &item.on_hand {
+            on_hand.add(amount);
+        }
+        if on_hand.is_empty() {
+            eprintln!("\t{} — some", item.name);
+        } else {
+            eprintln!("\t{} — {}", item.name, on_hand);
+        }
+    }
+    eprintln!();
+}
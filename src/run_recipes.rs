@@ -1,18 +1,23 @@
+use crate::joined;
 use crate::Groceries;
+use crate::Ingredient;
+use crate::ReadError;
+use crate::Store;
 use clap::ArgMatches;
+use std::collections::BTreeMap;
+use std::str::FromStr;
 
-pub fn run(sync_matches: &ArgMatches) -> Result<(), crate::ReadError> {
-    let path = sync_matches.get_one::<String>("path").unwrap();
-
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store) -> Result<(), crate::ReadError> {
     match sync_matches.subcommand() {
-        Some(("add", s_matches)) => recipes_add(s_matches, path)?,
-        Some(("delete", s_matches)) => recipes_delete(s_matches, path)?,
-        _ => recipes_print(sync_matches, path)?,
+        Some(("add", s_matches)) => recipes_add(s_matches, store)?,
+        Some(("delete", s_matches)) => recipes_delete(s_matches, store)?,
+        Some(("edit", s_matches)) => recipes_edit(s_matches, store)?,
+        _ => recipes_print(sync_matches, store)?,
     }
     Ok(())
 }
 
-fn recipes_add(s_matches: &ArgMatches, path: &str) -> Result<(), crate::ReadError> {
+fn recipes_add(s_matches: &ArgMatches, store: &dyn Store) -> Result<(), crate::ReadError> {
     let name_elems: Vec<_> = s_matches
         .values_of("name")
         .expect("name is required")
@@ -25,39 +30,197 @@ fn recipes_add(s_matches: &ArgMatches, path: &str) -> Result<(), crate::ReadErro
         .collect();
     let i = ingredient_vec.join(", ");
     eprintln!("Ingredients: {}", i);
-    let mut g = Groceries::from_path(path)?;
-    eprintln!("before adding: {:?}", g.recipes);
+    let mut sections = BTreeMap::new();
+    for section in s_matches.values_of("section").into_iter().flatten() {
+        let (item, section) = section.split_once('=').ok_or(ReadError::ParseInputError)?;
+        sections.insert(item.trim().to_string(), section.trim().to_string());
+    }
+    let mut g = store.load_groceries()?;
+    // before asking about sections for its ingredients
+    if g.has_recipe(&n) {
+        return Err(ReadError::RecipeExists);
+    }
+    let new_items = g.check_recipe_ingredients(&i, &sections)?;
+    if !new_items.is_empty() {
+        eprintln!("New to the library:");
+        for item in new_items {
+            eprintln!("{} ({})", item, item.section);
+        }
+    }
     g.add_recipe(&n, &i)?;
-    eprintln!("after adding: {:?}", g.recipes);
-    g.save(path)?;
+    print_recipe(&g, &n)?;
+    store.save_groceries(&g)?;
     Ok(())
 }
 
-fn recipes_delete(s_matches: &ArgMatches, path: &str) -> Result<(), crate::ReadError> {
+fn recipes_delete(s_matches: &ArgMatches, store: &dyn Store) -> Result<(), crate::ReadError> {
     let name_elems: Vec<_> = s_matches
         .values_of("name")
         .expect("name is required")
         .collect();
     let n = name_elems.join(" ");
     eprintln!("Recipe: {}", n);
-    let mut g = Groceries::from_path(path)?;
-    eprintln!("before deleting: {:?}", g.recipes);
+    let mut g = store.load_groceries()?;
+    g.recipe(&n).map_err(|e| did_you_mean(&g, e, &n, &[]))?;
     g.delete_recipe(&n)?;
-    eprintln!("after: {:?}", g.recipes);
-    g.save(path)?;
+    store.save_groceries(&g)?;
     Ok(())
 }
 
-fn recipes_print(sync_matches: &ArgMatches, path: &str) -> Result<(), crate::ReadError> {
-    let groceries = Groceries::from_path(path)?;
-    if let Ok(Some(name)) = sync_matches.try_get_one::<String>("recipe") {
-        eprintln!();
-        eprintln!("Recipe: {name}");
-        eprintln!("Ingredients:");
-        for ingredient in groceries.recipe_ingredients(name) {
-            eprintln!("{}", ingredient);
+fn recipes_edit(s_matches: &ArgMatches, store: &dyn Store) -> Result<(), crate::ReadError> {
+    let name_elems: Vec<_> = s_matches
+        .values_of("recipe")
+        .expect("recipe is required")
+        .collect();
+    let n = name_elems.join(" ");
+    let mut g = store.load_groceries()?;
+    g.recipe(&n).map_err(|e| did_you_mean(&g, e, &n, &[]))?;
+    match s_matches.subcommand() {
+        Some(("add", i_matches)) => {
+            let ingredient = joined(i_matches, "ingredient");
+            let name = Ingredient::from_str(&ingredient)?.name.0;
+            g.add_ingredient(&n, &ingredient)
+                .map_err(|e| did_you_mean(&g, e, &n, &[&name]))?
         }
-        eprintln!();
+        Some(("delete", i_matches)) => {
+            let ingredient = joined(i_matches, "ingredient");
+            g.delete_ingredient(&n, &ingredient)
+                .map_err(|e| did_you_mean(&g, e, &n, &[&ingredient]))?
+        }
+        Some(("edit", i_matches)) => {
+            let (ingredient, new) = (joined(i_matches, "ingredient"), joined(i_matches, "new"));
+            let name = Ingredient::from_str(&new)?.name.0;
+            g.edit_ingredient(&n, &ingredient, &new)
+                .map_err(|e| did_you_mean(&g, e, &n, &[&ingredient, &name]))?
+        }
+        Some(("set", d_matches)) => recipes_set(d_matches, g.recipe_mut(&n)?),
+        Some(("add-step", d_matches)) => g
+            .recipe_mut(&n)?
+            .add_instruction(&joined(d_matches, "step"), d_matches.get_one("at").copied())?,
+        Some(("delete-step", d_matches)) => g
+            .recipe_mut(&n)?
+            .delete_instruction(*d_matches.get_one("step").expect("step is required"))?,
+        _ => return Err(crate::ReadError::ParseInputError),
+    }
+    print_recipe(&g, &n)?;
+    store.save_groceries(&g)?;
+    Ok(())
+}
+
+/// Puts the closest names in the library to a recipe or item that
+/// isn't there in the error, if anything comes close.
+fn did_you_mean(g: &Groceries, error: ReadError, recipe: &str, items: &[&str]) -> ReadError {
+    let suggestions: Vec<String> = match error {
+        ReadError::RecipeNotFound => g
+            .get_recipe_matches(recipe)
+            .map(|r| format!("'{}'", r))
+            .take(3)
+            .collect(),
+        ReadError::ItemNotFound => items
+            .iter()
+            .find(|name| !g.collection.iter().any(|item| item.name.0 == **name))
+            .map(|name| {
+                g.get_item_matches(name)
+                    .map(|item| format!("'{}'", item))
+                    .take(3)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    if suggestions.is_empty() {
+        error
+    } else {
+        ReadError::DidYouMean {
+            error: error.to_string(),
+            suggestions: suggestions.join(" or "),
+        }
+    }
+}
+
+fn recipes_set(d_matches: &ArgMatches, recipe: &mut crate::RecipeRecord) {
+    // 0 or an empty value clears a detail
+    let number = |id: &str| {
+        d_matches
+            .get_one::<u32>(id)
+            .map(|n| Some(*n).filter(|n| *n > 0))
+    };
+    let text = |id: &str| {
+        d_matches
+            .values_of(id)
+            .map(|values| Some(values.collect::<Vec<_>>().join(" ")).filter(|s| !s.is_empty()))
+    };
+    if let Some(servings) = number("servings") {
+        recipe.servings = servings;
+    }
+    if let Some(prep_time) = number("prep time") {
+        recipe.prep_time = prep_time;
+    }
+    if let Some(cook_time) = number("cook time") {
+        recipe.cook_time = cook_time;
+    }
+    if let Some(tags) = d_matches.values_of("tags") {
+        recipe.tags = tags.filter(|t| !t.is_empty()).map(String::from).collect();
+    }
+    if let Some(source) = text("source") {
+        recipe.source = source;
+    }
+    if let Some(notes) = text("notes") {
+        recipe.notes = notes;
+    }
+}
+
+fn minutes(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{} min", m),
+        (h, 0) => format!("{} h", h),
+        (h, m) => format!("{} h {} min", h, m),
+    }
+}
+
+fn print_recipe(groceries: &crate::Groceries, name: &str) -> Result<(), crate::ReadError> {
+    let recipe = groceries.recipe(name)?;
+    eprintln!();
+    eprintln!("Recipe: {}", recipe);
+    if let Some(servings) = recipe.servings {
+        eprintln!("Serves: {}", servings);
+    }
+    if let Some(prep_time) = recipe.prep_time {
+        eprintln!("Prep time: {}", minutes(prep_time));
+    }
+    if let Some(cook_time) = recipe.cook_time {
+        eprintln!("Cook time: {}", minutes(cook_time));
+    }
+    if !recipe.tags.is_empty() {
+        eprintln!("Tags: {}", recipe.tags.join(", "));
+    }
+    if let Some(source) = &recipe.source {
+        eprintln!("Source: {}", source);
+    }
+    eprintln!("Ingredients:");
+    for ingredient in groceries.recipe_ingredients(name) {
+        match recipe.amount(&ingredient.id) {
+            Some(amount) => eprintln!("{} — {}", ingredient, amount),
+            None => eprintln!("{}", ingredient),
+        }
+    }
+    if !recipe.instructions.is_empty() {
+        eprintln!("Instructions:");
+        for (i, step) in recipe.instructions.iter().enumerate() {
+            eprintln!("{}. {}", i + 1, step);
+        }
+    }
+    if let Some(notes) = &recipe.notes {
+        eprintln!("Notes: {}", notes);
+    }
+    eprintln!();
+    Ok(())
+}
+
+fn recipes_print(sync_matches: &ArgMatches, store: &dyn Store) -> Result<(), crate::ReadError> {
+    let groceries = store.load_groceries()?;
+    if let Ok(Some(name)) = sync_matches.try_get_one::<String>("recipe") {
+        print_recipe(&groceries, name).map_err(|e| did_you_mean(&groceries, e, name, &[]))?;
     } else {
         eprintln!();
         eprintln!("Here are our recipes:");
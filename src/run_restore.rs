@@ -0,0 +1,45 @@
+use crate::ReadError;
+use clap::ArgMatches;
+use std::path::Path;
+
+pub fn run(sync_matches: &ArgMatches, path: &str, keep: usize) -> Result<(), ReadError> {
+    let backups = crate::backups(path)?;
+    if backups.is_empty() {
+        eprintln!("No backups of {} found", path);
+        return Ok(());
+    }
+
+    eprintln!("Backups of {}:", path);
+    for (i, backup) in backups.iter().enumerate() {
+        eprintln!("*{}* {}", i + 1, backup_name(backup));
+    }
+
+    let choice = match sync_matches.get_one::<usize>("backup") {
+        Some(number) => *number,
+        None => {
+            eprintln!(
+                "Enter the number of the backup to restore\n\
+                *any other key* to exit"
+            );
+            match crate::get_user_input()?.parse::<usize>() {
+                Ok(number) => number,
+                Err(_) => return Ok(()),
+            }
+        }
+    };
+
+    let backup = choice
+        .checked_sub(1)
+        .and_then(|i| backups.get(i))
+        .ok_or(ReadError::BackupNotFound)?;
+    crate::restore(path, backup, keep)?;
+    eprintln!("Restored {} from {}", path, backup_name(backup));
+    Ok(())
+}
+
+fn backup_name(backup: &Path) -> String {
+    backup
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,57 @@
+use crate::joined;
+use crate::Config;
+use crate::Groceries;
+use crate::ReadError;
+use crate::Store;
+use clap::ArgMatches;
+
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store, config: &Config) -> Result<(), ReadError> {
+    let mut groceries = if store.has_groceries()? {
+        store.load_groceries()?
+    } else {
+        Groceries::new_initialized()?
+    };
+    groceries.fill_sections(&config.sections);
+    match sync_matches.subcommand() {
+        Some(("add", s_matches)) => {
+            groceries.add_section(&joined(s_matches, "name"), s_matches.get_one("at").copied())?
+        }
+        Some(("remove", s_matches)) => {
+            let move_to = s_matches
+                .values_of("move to")
+                .map(|values| values.collect::<Vec<_>>().join(" "));
+            groceries.remove_section(&joined(s_matches, "name"), move_to.as_deref())?
+        }
+        Some(("rename", s_matches)) => {
+            groceries.rename_section(&joined(s_matches, "name"), &joined(s_matches, "new"))?
+        }
+        Some(("reorder", s_matches)) => {
+            let order: Vec<_> = s_matches
+                .values_of("order")
+                .expect("order is required")
+                .collect();
+            groceries.reorder_sections(&order)?
+        }
+        _ => {
+            print_sections(&groceries);
+            return Ok(());
+        }
+    }
+    print_sections(&groceries);
+    store.save_groceries(&groceries)?;
+    Ok(())
+}
+
+fn print_sections(groceries: &Groceries) {
+    eprintln!();
+    eprintln!("Sections:");
+    for (i, section) in groceries.sections.iter().enumerate() {
+        let items = groceries
+            .collection
+            .iter()
+            .filter(|item| &item.section == section)
+            .count();
+        eprintln!("{}. {} ({} items)", i + 1, section, items);
+    }
+    eprintln!();
+}
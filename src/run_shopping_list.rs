@@ -1,15 +1,104 @@
+use crate::joined;
+use crate::Config;
 use crate::Groceries;
 use crate::GroceriesItem;
+use crate::GroceryStore;
+use crate::ItemId;
 use crate::ReadError;
 use crate::ShoppingList;
-use std::path::Path;
+use crate::Store;
+use clap::ArgMatches;
 
-pub fn run() -> Result<(), ReadError> {
-    if crate::Groceries::from_path("groceries.json").is_err() {
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store, config: &Config) -> Result<(), ReadError> {
+    if !store.has_groceries()? {
         return Err(ReadError::LibraryNotFound);
     } else {
+        let mut groceries = store.load_groceries()?;
+        groceries.fill_sections(&config.sections);
+        let shop = match sync_matches.values_of("grocery store") {
+            Some(name) => Some(
+                groceries
+                    .store(&name.collect::<Vec<_>>().join(" "))?
+                    .clone(),
+            ),
+            None => None,
+        };
+        let shop = shop.as_ref();
+        match sync_matches.subcommand() {
+            Some(("checklist", _)) => {
+                let mut sl = load_saved_list(store)?;
+                sl.check_checklist()?;
+                store.save_list(&sl)?;
+                sl.print(&groceries, shop);
+                return Ok(());
+            }
+            Some(("check", s_matches)) => {
+                let mut sl = load_saved_list(store)?;
+                sl.check_off(&joined(s_matches, "item"), &mut groceries)?;
+                store.save_list(&sl)?;
+                store.save_groceries(&groceries)?;
+                sl.print_missing(&groceries);
+                return Ok(());
+            }
+            Some(("show", _)) => {
+                load_saved_list(store)?.print(&groceries, shop);
+                return Ok(());
+            }
+            Some(("add-recipe", s_matches)) => {
+                let mut sl = load_saved_list(store)?;
+                let name = joined(s_matches, "recipe");
+                let recipe = groceries.recipe(&name)?;
+                // before the pantry check, which goes by the servings we want
+                if let (Some(wanted), Some(makes)) =
+                    (sync_matches.get_one::<u32>("servings"), recipe.servings)
+                {
+                    sl.set_servings(&recipe.id, *wanted, makes);
+                }
+                sl.add_recipe_from(&groceries, &name)?;
+                store.save_list(&sl)?;
+                sl.print(&groceries, shop);
+                return Ok(());
+            }
+            Some(("add-item", s_matches)) => {
+                let mut sl = load_saved_list(store)?;
+                let name = joined(s_matches, "item");
+                let item = groceries.lookup_item(&name)?;
+                if item.name.0.to_lowercase() != name.to_lowercase() {
+                    eprintln!("Adding {}, which '{}' is another name for", item, name);
+                }
+                sl.add_item(item.clone());
+                store.save_list(&sl)?;
+                sl.print(&groceries, shop);
+                return Ok(());
+            }
+            Some(("remove", s_matches)) => {
+                let mut sl = load_saved_list(store)?;
+                sl.remove(&joined(s_matches, "name"), &groceries)?;
+                store.save_list(&sl)?;
+                sl.print(&groceries, shop);
+                return Ok(());
+            }
+            Some(("clear", _)) => {
+                let mut sl = load_saved_list(store)?;
+                sl.clear();
+                store.save_list(&sl)?;
+                return Ok(());
+            }
+            Some(("new", _)) => {
+                // the old list is kept with the backups
+                store.save_list(&ShoppingList::new())?;
+                return Ok(());
+            }
+            Some(("shop", _)) => {
+                let mut sl = load_saved_list(store)?;
+                sl.shop(&mut groceries, shop, store)?;
+                sl.print_missing(&groceries);
+                return Ok(());
+            }
+            _ => {}
+        }
         let mut sl = ShoppingList::new();
-        if Path::new("list.json").exists() {
+        if store.has_list()? {
             eprintln!(
                 "\n\
         Use most recently saved list?\n\
@@ -17,24 +106,65 @@ pub fn run() -> Result<(), ReadError> {
         *any other key* for fresh list"
             );
             if crate::prompt_for_y()? {
-                let path = "list.json";
-                sl = ShoppingList::from_path(path)?;
+                sl = store.load_list()?;
             }
 
             // view list if using saved list
-            sl.prompt_view_list()?;
+            if config.prompts.view_list {
+                sl.prompt_view_list(&groceries, shop)?;
+            }
         }
-        sl.prompt_add_recipes()?;
+        sl.prompt_add_recipes(&groceries, sync_matches.get_one::<u32>("servings").copied())?;
+
+        sl.prompt_add_groceries(&groceries, shop)?;
 
-        sl.prompt_add_groceries()?;
+        if !sl.checklist.is_empty() {
+            eprintln!(
+                "Check for the items on the checklist now?\n\
+                *y*\n\
+                *any other key* to check later with `grusterylist list checklist`"
+            );
+            if crate::prompt_for_y()? {
+                sl.check_checklist()?;
+            }
+        }
 
-        sl.prompt_save_list()?;
+        sl.prompt_save_list(store, config.prompts.confirm_save, &groceries, shop)?;
     }
     Ok(())
 }
 
+fn load_saved_list(store: &dyn Store) -> Result<ShoppingList, ReadError> {
+    if !store.has_list()? {
+        return Err(ReadError::ListNotFound);
+    }
+    store.load_list()
+}
+
+fn prompt_servings(makes: u32) -> Result<u32, ReadError> {
+    loop {
+        eprintln!(
+            "How many servings?\n\
+            *enter* for {}",
+            makes
+        );
+        let input = crate::get_user_input()?;
+        if input.is_empty() {
+            return Ok(makes);
+        }
+        match input.parse::<u32>() {
+            Ok(wanted) if wanted > 0 => return Ok(wanted),
+            _ => eprintln!("re-enter number of servings"),
+        }
+    }
+}
+
 impl ShoppingList {
-    pub(crate) fn prompt_view_list(&self) -> Result<(), ReadError> {
+    pub(crate) fn prompt_view_list(
+        &self,
+        groceries: &Groceries,
+        shop: Option<&GroceryStore>,
+    ) -> Result<(), ReadError> {
         if !self.groceries.is_empty() {
             eprintln!(
                 "\n\
@@ -44,14 +174,20 @@ impl ShoppingList {
             );
 
             if crate::prompt_for_y()? {
-                self.print();
+                self.print(groceries, shop);
                 println!();
             }
         }
         Ok(())
     }
 
-    pub(crate) fn prompt_add_recipes(&mut self) -> Result<(), ReadError> {
+    /// Offers each recipe in turn, asking how many servings we want of the
+    /// ones that say how many they make, unless `servings` says already.
+    pub(crate) fn prompt_add_recipes(
+        &mut self,
+        groceries: &Groceries,
+        servings: Option<u32>,
+    ) -> Result<(), ReadError> {
         eprintln!(
             "Add recipe ingredients to our list?\n\
                 *y*\n\
@@ -59,9 +195,7 @@ impl ShoppingList {
         );
 
         while crate::prompt_for_y()? {
-            let groceries = crate::Groceries::from_path("groceries.json")?;
-
-            for recipe in groceries.recipes.into_iter() {
+            for recipe in groceries.recipes() {
                 eprintln!(
                     "Shall we add ...\n\
                             {}?\n\
@@ -73,8 +207,13 @@ impl ShoppingList {
 
                 match crate::get_user_input()?.as_str() {
                     "y" => {
-                        if !self.recipes.contains(&recipe) {
-                            self.add_recipe(recipe);
+                        self.add_recipe(recipe.id.clone());
+                        if let Some(makes) = recipe.servings {
+                            let wanted = match servings {
+                                Some(wanted) => wanted,
+                                None => prompt_servings(makes)?,
+                            };
+                            self.set_servings(&recipe.id, wanted, makes);
                         }
                     }
                     "s" => break,
@@ -90,7 +229,11 @@ impl ShoppingList {
         Ok(())
     }
 
-    pub(crate) fn prompt_add_groceries(&mut self) -> Result<(), ReadError> {
+    pub(crate) fn prompt_add_groceries(
+        &mut self,
+        groceries: &Groceries,
+        shop: Option<&GroceryStore>,
+    ) -> Result<(), ReadError> {
         eprintln!(
             "Add groceries to shopping list?\n\
             *y*\n\
@@ -98,7 +241,7 @@ impl ShoppingList {
         );
 
         while crate::prompt_for_y()? {
-            self.add_groceries()?;
+            self.add_groceries(groceries, shop)?;
             eprintln!(
                 "Add more groceries to shopping list?\n\
             *y*\n\
@@ -108,19 +251,29 @@ impl ShoppingList {
         Ok(())
     }
 
-    pub(crate) fn add_groceries(&mut self) -> Result<(), ReadError> {
-        // move everything off list to temp list
-        let list_items: Vec<GroceriesItem> = self.groceries.drain(..).collect();
+    /// Goes through the library a section at a time, or aisle by aisle in
+    /// `shop`'s walking order leaving out what it doesn't carry.
+    pub(crate) fn add_groceries(
+        &mut self,
+        groceries: &Groceries,
+        shop: Option<&GroceryStore>,
+    ) -> Result<(), ReadError> {
+        // move everything off list to temp list, picking up any changes
+        // made to those items in the library, such as their amounts
+        let list_items: Vec<GroceriesItem> = self
+            .groceries
+            .drain(..)
+            .map(|item| groceries.item_by_id(&item.id).cloned().unwrap_or(item))
+            .collect();
         assert!(self.groceries.is_empty());
-        let sections = vec!["fresh", "pantry", "dairy", "protein", "freezer"];
-        let groceries = Groceries::from_path("groceries.json")?;
         let groceries_by_section: Vec<Vec<GroceriesItem>> = {
-            sections
-                .into_iter()
+            groceries
+                .sections
+                .iter()
                 .map(|section| {
                     let mut a: Vec<GroceriesItem> = list_items
                         .iter()
-                        .filter(|groceriesitem| groceriesitem.section.0 == section)
+                        .filter(|groceriesitem| &groceriesitem.section == section)
                         .cloned()
                         .collect();
 
@@ -128,7 +281,7 @@ impl ShoppingList {
                         .collection
                         .iter()
                         .filter(|groceriesitem| {
-                            groceriesitem.section.0 == section && !a.contains(groceriesitem)
+                            &groceriesitem.section == section && !a.contains(groceriesitem)
                         })
                         .cloned()
                         .collect();
@@ -137,20 +290,29 @@ impl ShoppingList {
                 })
                 .collect()
         };
-        for section in groceries_by_section {
+        let mut walk: Vec<&GroceriesItem> = groceries_by_section.iter().flatten().collect();
+        if let Some(shop) = shop {
+            // keep what's already on the list for when we shop somewhere else
+            for item in list_items.iter().filter(|item| !shop.carries(item)) {
+                self.add_groceries_item(item.clone());
+            }
+            walk = shop.walk(walk);
+        }
+        // items next to each other in the same section are asked about together
+        for section in walk.chunk_by(|a, b| a.section == b.section) {
             if !section.is_empty() {
-                for groceriesitem in &section {
-                    if !self.groceries.contains(groceriesitem)
-                        && groceriesitem
-                            .recipes
-                            .iter()
-                            .any(|recipe| self.recipes.contains(&*recipe))
+                for groceriesitem in section {
+                    if !self.contains(groceriesitem)
+                        && self
+                            .recipes(groceries)
+                            .any(|recipe| recipe.uses(&groceriesitem.id))
+                        && !self.in_pantry(groceriesitem, groceries)
                     {
-                        self.add_groceries_item(groceriesitem.clone());
+                        self.add_item((*groceriesitem).clone());
                     }
                 }
                 for groceriesitem in section {
-                    if !self.groceries.contains(&groceriesitem) {
+                    if !self.contains(groceriesitem) {
                         eprintln!(
                             "Do we need {}?\n\
                               *y*\n\
@@ -160,11 +322,7 @@ impl ShoppingList {
                         );
 
                         match crate::get_user_input()?.as_str() {
-                            "y" => {
-                                if !self.groceries.contains(&groceriesitem) {
-                                    self.add_groceries_item(groceriesitem.clone());
-                                }
-                            }
+                            "y" => self.add_item((*groceriesitem).clone()),
                             "s" => break,
                             &_ => continue,
                         }
@@ -175,20 +333,98 @@ impl ShoppingList {
         Ok(())
     }
 
-    pub(crate) fn prompt_save_list(&mut self) -> Result<(), ReadError> {
-        // don't save list if empty
-        if !self.checklist.is_empty() && !self.groceries.is_empty() && !self.recipes.is_empty() {
+    /// Asks about each item on the checklist, moving the ones we need
+    /// onto the list and dropping the ones we have.
+    pub(crate) fn check_checklist(&mut self) -> Result<(), ReadError> {
+        let items: Vec<(ItemId, String)> = self
+            .checklist
+            .iter()
+            .map(|item| (item.id.clone(), item.name.0.clone()))
+            .collect();
+        for (id, name) in items {
+            eprintln!(
+                "Do we need {}?\n\
+                *y* to put it on the list\n\
+                *n* if we have it\n\
+                *any other key* to leave it on the checklist",
+                name.to_lowercase()
+            );
+            match crate::get_user_input()?.as_str() {
+                "y" => self.confirm_checklist_item(&id)?,
+                "n" => self.delete_checklist_item(&id),
+                &_ => continue,
+            }
+        }
+        Ok(())
+    }
+
+    /// Goes through what we haven't bought yet in the order we walk the
+    /// store, saving the list and the pantry after every item we get.
+    pub(crate) fn shop(
+        &mut self,
+        groceries: &mut Groceries,
+        shop: Option<&GroceryStore>,
+        store: &dyn Store,
+    ) -> Result<(), ReadError> {
+        let mut walk: Vec<&GroceriesItem> = self.missing().collect();
+        walk.sort_by_key(|item| {
+            groceries
+                .sections
+                .iter()
+                .position(|section| section == &item.section)
+                .unwrap_or(groceries.sections.len())
+        });
+        if let Some(shop) = shop {
+            walk = shop.walk(walk);
+        }
+        let walk: Vec<(String, String)> = walk
+            .iter()
+            .map(|item| (item.name.0.clone(), self.describe(item, groceries)))
+            .collect();
+
+        for (name, description) in walk {
             eprintln!(
-                "Save current list?\n\
+                "Got {}?\n\
                 *y*\n\
-                *any other key* to continue"
+                *s* to stop shopping\n\
+                *any other key* for next item",
+                description
             );
+            match crate::get_user_input()?.as_str() {
+                "y" => {
+                    self.check_off(&name, groceries)?;
+                    store.save_list(self)?;
+                    store.save_groceries(groceries)?;
+                }
+                "s" => break,
+                &_ => continue,
+            }
+        }
+        Ok(())
+    }
 
-            if crate::prompt_for_y()? {
-                self.save()?;
+    pub(crate) fn prompt_save_list(
+        &mut self,
+        store: &dyn Store,
+        confirm_save: bool,
+        groceries: &Groceries,
+        shop: Option<&GroceryStore>,
+    ) -> Result<(), ReadError> {
+        // don't save list if empty
+        if !self.is_empty() {
+            if confirm_save {
+                eprintln!(
+                    "Save current list?\n\
+                    *y*\n\
+                    *any other key* to continue"
+                );
+            }
+
+            if !confirm_save || crate::prompt_for_y()? {
+                store.save_list(self)?;
             }
 
-            self.print();
+            self.print(groceries, shop);
         }
         Ok(())
     }
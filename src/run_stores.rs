@@ -0,0 +1,73 @@
+use crate::joined;
+use crate::Config;
+use crate::Groceries;
+use crate::ReadError;
+use crate::Store;
+use crate::StoreEntry;
+use clap::ArgMatches;
+
+pub fn run(sync_matches: &ArgMatches, store: &dyn Store, config: &Config) -> Result<(), ReadError> {
+    let mut groceries = if store.has_groceries()? {
+        store.load_groceries()?
+    } else {
+        Groceries::new_initialized()?
+    };
+    groceries.fill_sections(&config.sections);
+    match sync_matches.subcommand() {
+        Some(("add", s_matches)) => groceries.add_store(&joined(s_matches, "name"))?,
+        Some(("remove", s_matches)) => groceries.remove_store(&joined(s_matches, "name"))?,
+        Some(("aisles", s_matches)) => {
+            let order: Vec<_> = s_matches
+                .values_of("order")
+                .expect("order is required")
+                .collect();
+            groceries.set_store_aisles(&joined(s_matches, "name"), &order)?
+        }
+        Some(("carries", s_matches)) => {
+            let stock: Vec<_> = s_matches
+                .values_of("stock")
+                .map(|values| values.collect())
+                .unwrap_or_default();
+            groceries.set_store_carries(&joined(s_matches, "name"), &stock)?
+        }
+        _ => {
+            print_stores(&groceries);
+            return Ok(());
+        }
+    }
+    print_stores(&groceries);
+    store.save_groceries(&groceries)?;
+    Ok(())
+}
+
+fn print_stores(groceries: &Groceries) {
+    eprintln!();
+    eprintln!("Stores:");
+    for shop in groceries.stores.iter() {
+        eprintln!("{}", shop);
+        if !shop.aisles.is_empty() {
+            eprintln!("\taisles: {}", names(groceries, &shop.aisles));
+        }
+        if shop.carries.is_empty() {
+            eprintln!("\tcarries: everything");
+        } else {
+            eprintln!("\tcarries: {}", names(groceries, &shop.carries));
+        }
+    }
+    eprintln!();
+}
+
+// what the library calls the sections and items a store names now
+fn names(groceries: &Groceries, entries: &[StoreEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| match entry {
+            StoreEntry::Section(section) => section.0.clone(),
+            StoreEntry::Item(id) => match groceries.item_by_id(id) {
+                Some(item) => item.name.0.clone(),
+                None => id.0.clone(),
+            },
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,167 @@
+/// How alike two names have to be to count as a match.
+pub const MATCH_THRESHOLD: f64 = 0.7;
+
+/// How alike two names in the library have to be to look like the same
+/// item twice.
+pub const DUPLICATE_THRESHOLD: f64 = 0.8;
+
+/// How alike `query` and `name` are, from 0 for nothing in common to 1 for
+/// the same name. Takes the best of how much of one is contained in the
+/// other, how few typos apart they are and how many of their words match.
+pub fn similarity(query: &str, name: &str) -> f64 {
+    let query = query.trim().to_lowercase();
+    let name = name.trim().to_lowercase();
+    if query.is_empty() || name.is_empty() {
+        return 0.0;
+    }
+    if query == name {
+        return 1.0;
+    }
+    [
+        substring(&query, &name),
+        typos(&query, &name),
+        word_overlap(&query, &name),
+    ]
+    .into_iter()
+    .fold(0.0, f64::max)
+}
+
+/// The items of `candidates` whose name is like `query`, best match first.
+pub fn search<'a, T, F>(
+    query: &str,
+    candidates: impl IntoIterator<Item = &'a T>,
+    name: F,
+) -> Vec<&'a T>
+where
+    F: Fn(&T) -> &str,
+{
+    rank(candidates, |candidate| similarity(query, name(candidate)))
+}
+
+/// The items of `candidates` that `score` at least [`MATCH_THRESHOLD`], best
+/// first.
+pub fn rank<'a, T, F>(candidates: impl IntoIterator<Item = &'a T>, score: F) -> Vec<&'a T>
+where
+    F: Fn(&T) -> f64,
+{
+    let mut matches: Vec<(f64, &T)> = candidates
+        .into_iter()
+        .map(|candidate| (score(candidate), candidate))
+        .filter(|(score, _)| *score >= MATCH_THRESHOLD)
+        .collect();
+    matches.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    matches
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+// "tomatoe" in "tomatoes", scored by how much of the longer one it covers.
+// It has to start a word: "tea" isn't in "steak", nor "rice" in "licorice".
+fn substring(query: &str, name: &str) -> f64 {
+    let (shorter, longer) = if query.len() <= name.len() {
+        (query, name)
+    } else {
+        (name, query)
+    };
+    let starts_word = |i: usize| {
+        longer[..i]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric())
+    };
+    if longer.match_indices(shorter).any(|(i, _)| starts_word(i)) {
+        0.6 + 0.3 * shorter.chars().count() as f64 / longer.chars().count() as f64
+    } else {
+        0.0
+    }
+}
+
+// "tomatos" for "tomatoes", scored by edit distance
+fn typos(query: &str, name: &str) -> f64 {
+    let longest = query.chars().count().max(name.chars().count());
+    1.0 - edit_distance(query, name) as f64 / longest as f64
+}
+
+// "oil olive" for "olive oil", counting words that are at most a typo apart
+// for as much as they're alike
+fn word_overlap(query: &str, name: &str) -> f64 {
+    let query: Vec<&str> = query.split_whitespace().collect();
+    let name: Vec<&str> = name.split_whitespace().collect();
+    let shared: f64 = query
+        .iter()
+        .map(|q| {
+            name.iter()
+                .map(|n| typos(q, n))
+                .filter(|score| *score >= 0.75)
+                .fold(0.0, f64::max)
+        })
+        .sum();
+    // a little short of the same name, even when every word matches
+    0.95 * 2.0 * shared / (query.len() + name.len()) as f64
+}
+
+/// The number of single character insertions, deletions and substitutions
+/// it takes to turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(edit_distance("tomatos", "tomatoes"), 1);
+        assert_eq!(edit_distance("", "kale"), 4);
+        assert_eq!(similarity("Olive Oil", "olive oil"), 1.0);
+        for (query, name) in [
+            ("tomatoe", "tomatoes"),
+            ("tomatos", "tomatoes"),
+            ("oil olive", "olive oil"),
+            ("chiken breast", "chicken breasts"),
+            ("oil", "olive oil"),
+        ] {
+            assert!(
+                similarity(query, name) >= MATCH_THRESHOLD,
+                "{} ~ {}",
+                query,
+                name
+            );
+        }
+        for (query, name) in [
+            ("tomato", "potato"),
+            ("kale", "milk"),
+            ("", "milk"),
+            ("tea", "steak"),
+            ("corn", "popcorn"),
+            ("rice", "licorice"),
+        ] {
+            assert!(
+                similarity(query, name) < MATCH_THRESHOLD,
+                "{} !~ {}",
+                query,
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let names = ["tomato paste", "potatoes", "tomatoes", "cherry tomatoes"];
+        let found = search("tomatoe", &names, |name| name);
+        assert_eq!(found, [&"tomatoes", &"cherry tomatoes"]);
+        assert!(search("bagels", &names, |name| name).is_empty());
+    }
+}
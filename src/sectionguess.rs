@@ -0,0 +1,304 @@
+use crate::{similarity, GroceriesItem, GroceriesItemSection, MATCH_THRESHOLD};
+
+/// How sure a guess at an item's section has to be to go without asking.
+pub const CONFIDENT_GUESS: f64 = 0.8;
+
+// what a guess from only the library, or only the keywords, is worth: enough
+// to suggest, never enough to go without asking. "peanut butter" isn't dairy.
+const ONE_SIDED: f64 = 0.7;
+
+/// The sections most libraries have, by the names they go by, with words
+/// that give an item away as belonging in them.
+const KEYWORDS: &[(&[&str], &[&str])] = &[
+    (
+        &[
+            "fresh",
+            "produce",
+            "fruit and vegetables",
+            "vegetables",
+            "fruit",
+            "greengrocer",
+        ],
+        &[
+            "apple",
+            "avocado",
+            "banana",
+            "basil",
+            "beans",
+            "berries",
+            "broccoli",
+            "cabbage",
+            "carrot",
+            "cauliflower",
+            "celery",
+            "chard",
+            "chili",
+            "cilantro",
+            "coriander",
+            "courgette",
+            "cucumber",
+            "dill",
+            "eggplant",
+            "garlic",
+            "ginger",
+            "grapes",
+            "herbs",
+            "kale",
+            "leek",
+            "lemon",
+            "lettuce",
+            "lime",
+            "mango",
+            "mint",
+            "mushroom",
+            "onion",
+            "orange",
+            "parsley",
+            "peach",
+            "pear",
+            "pepper",
+            "potato",
+            "rosemary",
+            "salad",
+            "scallion",
+            "shallot",
+            "spinach",
+            "squash",
+            "thyme",
+            "tomato",
+            "zucchini",
+        ],
+    ),
+    (
+        &[
+            "pantry",
+            "dry goods",
+            "staples",
+            "cupboard",
+            "baking",
+            "canned goods",
+        ],
+        &[
+            "baking",
+            "beans",
+            "broth",
+            "canned",
+            "chickpeas",
+            "cinnamon",
+            "cocoa",
+            "coffee",
+            "couscous",
+            "cumin",
+            "dried",
+            "flour",
+            "honey",
+            "lentils",
+            "noodles",
+            "nuts",
+            "oats",
+            "oil",
+            "paprika",
+            "pasta",
+            "paste",
+            "quinoa",
+            "rice",
+            "salt",
+            "sauce",
+            "spaghetti",
+            "spice",
+            "stock",
+            "sugar",
+            "tahini",
+            "tea",
+            "tinned",
+            "vinegar",
+            "yeast",
+        ],
+    ),
+    (
+        &[
+            "protein",
+            "meat",
+            "meat and fish",
+            "butcher",
+            "fish",
+            "seafood",
+            "deli",
+        ],
+        &[
+            "bacon", "beef", "chicken", "chorizo", "cod", "fish", "ham", "lamb", "mince", "pork",
+            "prawns", "salmon", "sausage", "shrimp", "steak", "tofu", "tuna", "turkey",
+        ],
+    ),
+    (
+        &["dairy", "dairy and eggs", "eggs", "fridge", "chilled"],
+        &[
+            "butter",
+            "cheddar",
+            "cheese",
+            "cream",
+            "eggs",
+            "feta",
+            "milk",
+            "mozzarella",
+            "parmesan",
+            "parmigiana",
+            "ricotta",
+            "yogurt",
+            "yoghurt",
+        ],
+    ),
+    (&["freezer", "frozen", "frozen food"], &["frozen", "ice"]),
+    (
+        &["bakery", "bread"],
+        &["bagel", "baguette", "bread", "buns", "rolls", "tortillas"],
+    ),
+];
+
+/// Words that say where an item is kept, which outweigh what the item is:
+/// "frozen peas" go in the freezer.
+const KEPT: &[&str] = &["frozen", "canned", "tinned", "dried"];
+
+/// A guess at which of `sections` the item called `name` belongs in, and how
+/// sure it is, from 0 to 1. It's only [`CONFIDENT_GUESS`] or more when an
+/// item in `library` with a like name and the keywords everyone's library
+/// shares agree. An item that only ends in the same word doesn't count, as
+/// that's the word the keywords went by too: "peanut butter" isn't dairy
+/// because "butter" is.
+pub fn guess_section<'a>(
+    name: &str,
+    sections: &'a [GroceriesItemSection],
+    library: &[GroceriesItem],
+) -> Option<(&'a GroceriesItemSection, f64)> {
+    let name = name.trim().to_lowercase();
+    let mut scores: Vec<(&GroceriesItemSection, f64)> = sections
+        .iter()
+        .map(|section| {
+            let (from_library, whole_name) = library
+                .iter()
+                .filter(|item| &item.section == section)
+                .map(|item| like_item(&name, &item.name.0))
+                .fold(
+                    (0.0, false),
+                    |best, like| if like.0 > best.0 { like } else { best },
+                );
+            let from_keywords = keywords(&name, &section.0);
+            let score = if whole_name && from_keywords > 0.0 {
+                // they agree
+                (from_library.max(from_keywords) + 0.1).min(1.0)
+            } else {
+                ONE_SIDED * from_library.max(from_keywords)
+            };
+            (section, score)
+        })
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+    scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    match scores.as_slice() {
+        [] => None,
+        // a tie is no better than a coin toss
+        [(best, score), (_, next), ..] if next >= score => Some((*best, score / 2.0)),
+        [(best, score), ..] => Some((*best, *score)),
+    }
+}
+
+// "goat cheese" goes where "cheddar cheese" is: like names, or names that
+// end in the same thing. Says whether more than the last word was alike,
+// as "peanut butter" is like "butter" only by the word the keywords see too.
+fn like_item(name: &str, other: &str) -> (f64, bool) {
+    let split = |s: &str| match s.trim().rsplit_once(char::is_whitespace) {
+        Some((rest, last)) => (rest.trim().to_string(), last.to_string()),
+        None => (String::new(), s.trim().to_string()),
+    };
+    let ((rest, last), (other_rest, other_last)) = (split(name), split(other));
+    let same_thing = if similarity(&last, &other_last) >= MATCH_THRESHOLD {
+        0.8
+    } else {
+        0.0
+    };
+    let score = similarity(name, other);
+    if score >= MATCH_THRESHOLD {
+        let more_alike = similarity(&rest, &other_rest) >= MATCH_THRESHOLD;
+        (score.max(same_thing), more_alike)
+    } else {
+        (same_thing, false)
+    }
+}
+
+// how well the words of `name` fit the keywords of `section`, if we know it
+fn keywords(name: &str, section: &str) -> f64 {
+    let section = section.to_lowercase();
+    let Some((_, keywords)) = KEYWORDS
+        .iter()
+        .find(|(names, _)| names.contains(&section.as_str()))
+    else {
+        return 0.0;
+    };
+    let is_keyword = |word: &str| {
+        keywords
+            .iter()
+            .any(|keyword| similarity(word, keyword) >= 0.85)
+    };
+    let words: Vec<&str> = name.split_whitespace().collect();
+    if words.iter().any(|word| KEPT.contains(word)) {
+        // only where it's kept counts
+        return if words
+            .iter()
+            .any(|word| KEPT.contains(word) && is_keyword(word))
+        {
+            0.9
+        } else {
+            0.0
+        };
+    }
+    if words.iter().any(|word| is_keyword(word)) {
+        0.8
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_guess_section() {
+        let sections: Vec<GroceriesItemSection> = ["fresh", "dry goods", "dairy", "freezer"]
+            .iter()
+            .map(|s| GroceriesItemSection(s.to_string()))
+            .collect();
+        let library = vec![
+            GroceriesItem::new("cheddar cheese", "dairy"),
+            GroceriesItem::new("tahini paste", "dry goods"),
+            GroceriesItem::new("kefir", "dairy"),
+            GroceriesItem::new("butter", "dairy"),
+            GroceriesItem::new("sour cream", "dairy"),
+        ];
+        let guess = |name: &str| {
+            guess_section(name, &sections, &library)
+                .map(|(section, score)| (section.0.as_str(), score >= CONFIDENT_GUESS))
+        };
+
+        // the library and the keywords agree
+        assert_eq!(guess("cheddar cheeses"), Some(("dairy", true)));
+        assert_eq!(guess("Cheddar Cheese"), Some(("dairy", true)));
+        // but only going by the same last word, which is one thing twice
+        assert_eq!(guess("goat cheese"), Some(("dairy", false)));
+        assert_eq!(guess("tomato paste"), Some(("fresh", false)));
+        assert_eq!(guess("peanut butter"), Some(("dairy", false)));
+        assert_eq!(guess("apple butter"), Some(("fresh", false)));
+        assert_eq!(guess("cocoa butter"), Some(("dry goods", false)));
+        assert_eq!(guess("ice cream"), Some(("dairy", false)));
+        // only one of them knows, so we're asked, with the guess to hand
+        assert_eq!(guess("greek yogurt"), Some(("dairy", false)));
+        assert_eq!(guess("frozen peas"), Some(("freezer", false)));
+        assert_eq!(guess("kefirs"), Some(("dairy", false)));
+        assert_eq!(guess("red lentils"), Some(("dry goods", false)));
+        assert_eq!(guess("coconut milk"), Some(("dairy", false)));
+        assert_eq!(guess("black pepper"), Some(("fresh", false)));
+        // fresh or dried?
+        assert_eq!(guess("beans"), Some(("fresh", false)));
+        assert_eq!(guess("dragon fruit"), None);
+    }
+}
@@ -1,12 +1,38 @@
-use crate::{GroceriesItem, GroceriesItemName, ReadError, Recipe};
+use crate::{
+    migrate_list, Amount, Groceries, GroceriesItem, GroceriesItemName, GroceryStore, ItemId,
+    ReadError, RecipeId, RecipeRecord, Total, LIST_SCHEMA_VERSION,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ShoppingList {
+    pub schema_version: u64,
     pub checklist: Vec<GroceriesItem>,
-    pub recipes: Vec<Recipe>,
+    /// Ids of the library's recipes we're shopping for
+    pub recipes: Vec<RecipeId>,
     pub groceries: Vec<GroceriesItem>,
+    /// Servings to shop for, by recipe id, for recipes we're not making as written
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub servings: BTreeMap<RecipeId, Servings>,
+    /// Ids of the groceries we've bought so far
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub purchased: BTreeSet<ItemId>,
+}
+
+/// How many servings of a recipe we want and how many the recipe makes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Servings {
+    pub wanted: u32,
+    pub makes: u32,
+}
+
+impl Servings {
+    /// What to multiply the recipe's amounts by.
+    pub fn scale(&self) -> f64 {
+        self.wanted as f64 / self.makes as f64
+    }
 }
 
 impl Default for ShoppingList {
@@ -22,19 +48,37 @@ impl ShoppingList {
 
     fn new_initialized() -> Self {
         ShoppingList {
+            schema_version: LIST_SCHEMA_VERSION,
             checklist: vec![],
             recipes: vec![],
             groceries: vec![],
+            servings: BTreeMap::new(),
+            purchased: BTreeSet::new(),
         }
     }
 
+    /// Reads a list on its own, without a library to take ids from
+    /// if it needs migrating; [`Store::load_list`] has one.
+    ///
+    /// [`Store::load_list`]: crate::Store::load_list
     pub fn from_path<P: AsRef<Path> + Copy>(path: P) -> Result<ShoppingList, ReadError> {
         let reader = crate::helpers::read(path)?;
 
-        Ok(serde_json::from_reader(reader)?)
+        ShoppingList::from_document(serde_json::from_reader(reader)?, None)
+    }
+
+    /// Upgrades a list read in any earlier schema version and deserializes it.
+    pub fn from_document(
+        mut document: serde_json::Value,
+        library: Option<&Groceries>,
+    ) -> Result<ShoppingList, ReadError> {
+        migrate_list(&mut document, library)?;
+        Ok(serde_json::from_value(document)?)
     }
 
-    pub fn print(&self) {
+    /// Prints the list, with the groceries in `store`'s walking order
+    /// and only those it carries if we're shopping somewhere in particular.
+    pub fn print(&self, groceries: &Groceries, store: Option<&GroceryStore>) {
         if !self.checklist.is_empty() {
             println!("Check if we need:");
 
@@ -45,77 +89,346 @@ impl ShoppingList {
         if !self.recipes.is_empty() {
             println!("recipes:");
 
-            self.recipes.iter().for_each(|recipe| {
-                println!("\t{}", recipe);
+            self.recipes.iter().for_each(|id| {
+                let name = match groceries.recipe_by_id(id) {
+                    Some(recipe) => recipe.to_string(),
+                    None => format!("{} (deleted from the library)", id),
+                };
+                match self.servings.get(id) {
+                    Some(servings) => println!("\t{} ({} servings)", name, servings.wanted),
+                    None => println!("\t{}", name),
+                }
             });
         }
-        if !self.groceries.is_empty() {
-            println!("groceries:");
+        let items = match store {
+            Some(store) => store.walk(&self.groceries),
+            None => self.groceries.iter().collect(),
+        };
+        if !items.is_empty() {
+            match store {
+                Some(store) => println!("groceries at {}:", store),
+                None => println!("groceries:"),
+            }
 
-            self.groceries.iter().for_each(|item| {
-                println!("\t{}", item.name.0.to_lowercase());
+            items.iter().for_each(|item| {
+                if self.is_purchased(item) {
+                    println!("\t{} (bought)", self.describe(item, groceries));
+                } else {
+                    println!("\t{}", self.describe(item, groceries));
+                }
             });
         }
     }
 
+    /// Prints what we still have to buy.
+    pub fn print_missing(&self, groceries: &Groceries) {
+        let missing: Vec<&GroceriesItem> = self.missing().collect();
+        if missing.is_empty() {
+            println!("Got everything on the list");
+        } else {
+            println!("Still missing:");
+            missing.iter().for_each(|item| {
+                println!("\t{}", self.describe(item, groceries));
+            });
+        }
+    }
+
+    /// The recipes on this list, as they are in the library now.
+    pub fn recipes<'a>(
+        &'a self,
+        groceries: &'a Groceries,
+    ) -> impl Iterator<Item = &'a RecipeRecord> {
+        self.recipes
+            .iter()
+            .filter_map(|id| groceries.recipe_by_id(id))
+    }
+
+    /// The recipes on this list that use `item`, and how much of it they
+    /// need altogether, scaled to the servings we want, less what we have
+    /// at home. Both come from the library, not from when they were added.
+    pub fn needed<'a>(
+        &'a self,
+        item: &GroceriesItem,
+        groceries: &'a Groceries,
+    ) -> (Vec<&'a RecipeRecord>, Total) {
+        let recipes: Vec<&RecipeRecord> = self
+            .recipes(groceries)
+            .filter(|recipe| recipe.uses(&item.id))
+            .collect();
+        let mut total = Total::default();
+        for recipe in &recipes {
+            if let Some(amount) = recipe.amount(&item.id) {
+                match self.servings.get(&recipe.id) {
+                    Some(servings) => total.add(&amount.scaled(servings.scale())),
+                    None => total.add(amount),
+                }
+            }
+        }
+        if let Some(on_hand) = on_hand(item, groceries) {
+            total.subtract(on_hand);
+        }
+        (recipes, total)
+    }
+
+    /// Whether what we have at home covers all the recipes on this list need of `item`.
+    pub fn in_pantry(&self, item: &GroceriesItem, groceries: &Groceries) -> bool {
+        let (recipes, total) = self.needed(item, groceries);
+        let measured = recipes.iter().any(|recipe| {
+            recipe
+                .amount(&item.id)
+                .is_some_and(|amount| amount.quantity.is_some())
+        });
+        on_hand(item, groceries).is_some() && measured && total.is_empty()
+    }
+
+    /// Marks `name` as bought and puts what we bought in the pantry.
+    pub fn check_off(&mut self, name: &str, groceries: &mut Groceries) -> Result<(), ReadError> {
+        let id = self
+            .item_id(name, groceries)
+            .ok_or(ReadError::ItemNotFound)?;
+        let item = self
+            .groceries
+            .iter()
+            .find(|item| item.id == id)
+            .ok_or(ReadError::ItemNotFound)?;
+        if self.is_purchased(item) {
+            return Ok(());
+        }
+        let bought = match self.needed(item, groceries).1.amounts().as_slice() {
+            [amount] => Some(amount.clone()),
+            _ => None,
+        };
+        let stock = groceries
+            .item_by_id_mut(&id)
+            .ok_or(ReadError::ItemNotFound)?;
+        // an amount in units the pantry can't add up still means we have some
+        stock
+            .restock(bought.as_ref())
+            .or_else(|_| stock.restock(None))?;
+        self.purchased.insert(id);
+        Ok(())
+    }
+
+    /// The id of the item `name` means, found the way the library finds
+    /// it, by an alias or in any case, or by what the list calls it if the
+    /// library doesn't have it anymore.
+    fn item_id(&self, name: &str, groceries: &Groceries) -> Option<ItemId> {
+        match groceries.lookup_item(name) {
+            Ok(item) => Some(item.id.clone()),
+            Err(_) => self
+                .groceries
+                .iter()
+                .chain(self.checklist.iter())
+                .find(|item| item.name.0.to_lowercase() == name.to_lowercase())
+                .map(|item| item.id.clone()),
+        }
+    }
+
+    pub fn is_purchased(&self, item: &GroceriesItem) -> bool {
+        self.purchased.contains(&item.id)
+    }
+
+    /// The groceries we haven't bought yet.
+    pub fn missing(&self) -> impl Iterator<Item = &GroceriesItem> {
+        self.groceries
+            .iter()
+            .filter(|item| !self.is_purchased(item))
+    }
+
+    /// e.g. "olive oil — 7 tbsp (tomato pasta, hummus)"
+    pub(crate) fn describe(&self, item: &GroceriesItem, groceries: &Groceries) -> String {
+        let mut description = item.name.0.to_lowercase();
+        let (recipes, total) = self.needed(item, groceries);
+        if !total.is_empty() {
+            description = format!("{} — {}", description, total);
+        }
+        if !recipes.is_empty() {
+            let recipes: Vec<&str> = recipes
+                .iter()
+                .map(|recipe| recipe.name.0.as_str())
+                .collect();
+            description = format!("{} ({})", description, recipes.join(", "));
+        }
+        description
+    }
+
     pub fn add_groceries_item(&mut self, item: GroceriesItem) {
         self.groceries.push(item)
     }
 
-    pub fn delete_groceries_item(&mut self, name: &str) -> Result<(), ReadError> {
-        if let Ok(i) = self
-            .groceries
+    /// Whether `item` is on the list or the checklist.
+    pub fn contains(&self, item: &GroceriesItem) -> bool {
+        self.groceries
             .iter()
-            .position(|x| x.name == GroceriesItemName(name.to_string()))
-            .ok_or(ReadError::ItemNotFound)
-        {
-            self.groceries.remove(i);
+            .chain(self.checklist.iter())
+            .any(|x| x.id == item.id)
+    }
+
+    /// Adds `item` to the groceries, or to the checklist if we check for it
+    /// at home first.
+    pub fn add_item(&mut self, item: GroceriesItem) {
+        if self.contains(&item) {
+            return;
+        }
+        if item.check_first {
+            self.add_checklist_item(item)
+        } else {
+            self.add_groceries_item(item)
         }
+    }
+
+    /// Moves an item we found we need from the checklist onto the list.
+    pub fn confirm_checklist_item(&mut self, id: &ItemId) -> Result<(), ReadError> {
+        let i = self
+            .checklist
+            .iter()
+            .position(|x| &x.id == id)
+            .ok_or(ReadError::ItemNotFound)?;
+        let item = self.checklist.remove(i);
+        self.add_groceries_item(item);
         Ok(())
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.checklist.is_empty() && self.recipes.is_empty() && self.groceries.is_empty()
+    }
+
+    pub fn delete_groceries_item(&mut self, id: &ItemId) {
+        self.groceries.retain(|x| &x.id != id);
+        self.purchased.remove(id);
+    }
+
     pub fn add_checklist_item(&mut self, item: GroceriesItem) {
         self.checklist.push(item)
     }
 
-    pub fn delete_checklist_item(&mut self, name: &str) -> Result<(), ReadError> {
-        if let Ok(i) = self
-            .checklist
-            .iter()
-            .position(|x| x.name == GroceriesItemName(name.to_string()))
-            .ok_or(ReadError::ItemNotFound)
-        {
-            self.checklist.remove(i);
+    pub fn delete_checklist_item(&mut self, id: &ItemId) {
+        self.checklist.retain(|x| &x.id != id)
+    }
+
+    pub fn add_recipe(&mut self, recipe: RecipeId) {
+        if !self.recipes.contains(&recipe) {
+            self.recipes.push(recipe)
         }
-        Ok(())
     }
 
-    pub fn add_recipe(&mut self, recipe: Recipe) {
-        self.recipes.push(recipe)
+    /// Adds the library's recipe `name` along with the ingredients we don't
+    /// have enough of at home.
+    pub fn add_recipe_from(&mut self, groceries: &Groceries, name: &str) -> Result<(), ReadError> {
+        let recipe = groceries.recipe(name)?;
+        self.add_recipe(recipe.id.clone());
+        for item in groceries.items().filter(|item| recipe.uses(&item.id)) {
+            if !self.in_pantry(item, groceries) {
+                self.add_item(item.clone());
+            }
+        }
+        Ok(())
     }
 
-    pub fn delete_recipe(&mut self, name: &str) -> Result<(), ReadError> {
-        if let Ok(i) = self
+    /// Takes a recipe, or an item on the list or checklist, off the list.
+    pub fn remove(&mut self, name: &str, groceries: &Groceries) -> Result<(), ReadError> {
+        let recipe = self
             .recipes
             .iter()
-            .position(|x| x == &Recipe(name.to_string()))
-            .ok_or(ReadError::ItemNotFound)
-        {
-            self.recipes.remove(i);
+            .find(|id| match groceries.recipe_by_id(id) {
+                Some(recipe) => recipe.name.0 == name,
+                // one deleted from the library goes by its id
+                None => id.0 == name,
+            })
+            .cloned();
+        if let Some(recipe) = recipe {
+            self.delete_recipe(&recipe);
+            return Ok(());
+        }
+        let id = self.item_id(name, groceries).ok_or(ReadError::NotOnList)?;
+        if self.groceries.iter().any(|item| item.id == id) {
+            self.delete_groceries_item(&id)
+        } else if self.checklist.iter().any(|item| item.id == id) {
+            self.delete_checklist_item(&id)
+        } else {
+            return Err(ReadError::NotOnList);
         }
         Ok(())
     }
 
+    /// Renames the item with id `id` wherever it is on the list, after it
+    /// was renamed in the library.
+    pub fn rename_item(&mut self, id: &ItemId, new_name: &str) {
+        for item in self
+            .groceries
+            .iter_mut()
+            .chain(self.checklist.iter_mut())
+            .filter(|item| &item.id == id)
+        {
+            item.name = GroceriesItemName(new_name.to_string());
+        }
+    }
+
+    /// Puts `keep` wherever the list had one of the items with ids `dupes`,
+    /// after they were merged into it in the library.
+    pub fn merge_items(&mut self, keep: &GroceriesItem, dupes: &[ItemId]) {
+        // it stays where the first of them was, on the list before the checklist
+        let mut found = false;
+        for items in [&mut self.groceries, &mut self.checklist] {
+            items.retain_mut(|item| {
+                if item.id != keep.id && !dupes.contains(&item.id) {
+                    return true;
+                }
+                if found {
+                    return false;
+                }
+                found = true;
+                *item = keep.clone();
+                true
+            });
+        }
+        for dupe in dupes {
+            if self.purchased.remove(dupe) {
+                self.purchased.insert(keep.id.clone());
+            }
+        }
+    }
+
+    /// Takes everything off the list.
+    pub fn clear(&mut self) {
+        *self = ShoppingList::new();
+    }
+
+    /// Shops for `wanted` servings of `recipe`, which makes `makes`.
+    pub fn set_servings(&mut self, recipe: &RecipeId, wanted: u32, makes: u32) {
+        if wanted == makes || wanted == 0 || makes == 0 {
+            self.servings.remove(recipe);
+        } else {
+            self.servings
+                .insert(recipe.clone(), Servings { wanted, makes });
+        }
+    }
+
+    pub fn delete_recipe(&mut self, recipe: &RecipeId) {
+        self.recipes.retain(|id| id != recipe);
+        self.servings.remove(recipe);
+    }
+
     pub fn to_json_string(&self) -> Result<String, ReadError> {
         Ok(serde_json::to_string(&self)?)
     }
 
-    pub fn save(&self) -> Result<(), ReadError> {
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ReadError> {
         let json = self.to_json_string()?;
-        crate::helpers::write("list.json", json)
+        crate::helpers::write(path, json)
     }
 }
 
+/// What the library says we have of `item` at home, or the list's copy
+/// if it's since been deleted from the library.
+fn on_hand<'a>(item: &'a GroceriesItem, groceries: &'a Groceries) -> Option<&'a Amount> {
+    groceries
+        .item_by_id(&item.id)
+        .unwrap_or(item)
+        .on_hand
+        .as_ref()
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -139,236 +452,135 @@ pub mod test {
         let file = create_test_json_file()?;
         let mut sl = ShoppingList::from_path(file.path())?;
         let item = GroceriesItem {
+            id: ItemId("kumquats".to_string()),
             name: GroceriesItemName("kumquats".to_string()),
             section: GroceriesItemSection("fresh".to_string()),
-            is_recipe_ingredient: false,
-            recipes: vec![],
+            aliases: vec![],
+            on_hand: None,
+            check_first: false,
         };
         sl.add_groceries_item(item);
         insta::assert_json_snapshot!(sl.groceries, @r###"
         [
           {
+            "id": "garlic",
             "name": "garlic",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "crispy sheet-pan noodles",
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes",
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "tomatoes",
             "name": "tomatoes",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "basil",
             "name": "basil",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "lemons",
             "name": "lemons",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "chicken breasts with lemon",
-              "hummus",
-              "sheet-pan chicken with jammy tomatoes",
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "pasta",
             "name": "pasta",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "olive-oil",
             "name": "olive oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "sheet-pan chicken with jammy tomatoes",
-              "turkey meatballs",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "short-grain-brown-rice",
             "name": "short grain brown rice",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "flue flighter chicken stew"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "parmigiana",
             "name": "parmigiana",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "turkey meatballs"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "eggs",
             "name": "eggs",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies",
-              "fried eggs for breakfast",
-              "turkey meatballs"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "sausages",
             "name": "sausages",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "protein"
           },
           {
+            "id": "dumplings",
             "name": "dumplings",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           },
           {
+            "id": "kumquats",
             "name": "kumquats",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           }
         ]
         "###);
-        sl.delete_groceries_item("kumquats")?;
+        sl.delete_groceries_item(&ItemId("kumquats".to_string()));
         insta::assert_json_snapshot!(sl.groceries, @r###"
         [
           {
+            "id": "garlic",
             "name": "garlic",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "crispy tofu with cashews and blistered snap peas",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "crispy sheet-pan noodles",
-              "flue flighter chicken stew",
-              "sheet-pan chicken with jammy tomatoes",
-              "swordfish pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "tomatoes",
             "name": "tomatoes",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "basil",
             "name": "basil",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "lemons",
             "name": "lemons",
-            "section": "fresh",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "chicken breasts with lemon",
-              "hummus",
-              "sheet-pan chicken with jammy tomatoes",
-              "flue flighter chicken stew"
-            ]
+            "section": "fresh"
           },
           {
+            "id": "pasta",
             "name": "pasta",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "olive-oil",
             "name": "olive oil",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "chicken breasts with lemon",
-              "hummus",
-              "tomato pasta",
-              "sheet-pan chicken with jammy tomatoes",
-              "turkey meatballs",
-              "swordfish pasta"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "short-grain-brown-rice",
             "name": "short grain brown rice",
-            "section": "pantry",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "Sheet Pan Salmon with Broccoli",
-              "flue flighter chicken stew"
-            ]
+            "section": "pantry"
           },
           {
+            "id": "parmigiana",
             "name": "parmigiana",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "tomato pasta",
-              "turkey meatballs"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "eggs",
             "name": "eggs",
-            "section": "dairy",
-            "is_recipe_ingredient": true,
-            "recipes": [
-              "oatmeal chocolate chip cookies",
-              "fried eggs for breakfast",
-              "turkey meatballs"
-            ]
+            "section": "dairy"
           },
           {
+            "id": "sausages",
             "name": "sausages",
-            "section": "protein",
-            "is_recipe_ingredient": true,
-            "recipes": []
+            "section": "protein"
           },
           {
+            "id": "dumplings",
             "name": "dumplings",
-            "section": "freezer",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "freezer"
           }
         ]
         "###);
@@ -380,41 +592,363 @@ pub mod test {
         let file = create_test_json_file()?;
         let mut sl = ShoppingList::from_path(file.path())?;
         let item = GroceriesItem {
+            id: ItemId("kumquats".to_string()),
             name: GroceriesItemName("kumquats".to_string()),
             section: GroceriesItemSection("fresh".to_string()),
-            is_recipe_ingredient: false,
-            recipes: vec![],
+            aliases: vec![],
+            on_hand: None,
+            check_first: false,
         };
         sl.add_checklist_item(item);
         insta::assert_json_snapshot!(sl.checklist, @r###"
         [
           {
+            "id": "kumquats",
             "name": "kumquats",
-            "section": "fresh",
-            "is_recipe_ingredient": false,
-            "recipes": []
+            "section": "fresh"
           }
         ]
         "###);
-        sl.delete_checklist_item("kumquats")?;
+        sl.delete_checklist_item(&ItemId("kumquats".to_string()));
         insta::assert_json_snapshot!(sl.checklist, @"[]");
         Ok(())
     }
 
+    #[test]
+    fn test_checklist() {
+        let mut sl = ShoppingList::new();
+        assert!(sl.is_empty());
+        let mut cumin = GroceriesItem::new("cumin", "spices");
+        cumin.check_first = true;
+        sl.add_item(cumin.clone());
+        sl.add_item(GroceriesItem::new("kale", "fresh"));
+        // already on the checklist
+        sl.add_item(cumin);
+        assert_eq!(sl.checklist.len(), 1);
+        assert_eq!(sl.groceries.len(), 1);
+        assert!(!sl.is_empty());
+
+        sl.confirm_checklist_item(&ItemId("cumin".to_string()))
+            .unwrap();
+        assert!(sl.checklist.is_empty());
+        let names: Vec<&str> = sl
+            .groceries
+            .iter()
+            .map(|item| item.name.0.as_str())
+            .collect();
+        assert_eq!(names, ["kale", "cumin"]);
+        assert!(matches!(
+            sl.confirm_checklist_item(&ItemId("cumin".to_string())),
+            Err(ReadError::ItemNotFound)
+        ));
+    }
+
     #[test]
     fn test_delete_recipe() -> Result<(), Box<dyn std::error::Error>> {
         let file = create_test_json_file()?;
         let mut sl = ShoppingList::from_path(file.path())?;
         insta::assert_json_snapshot!(sl.recipes, @r###"
         [
-          "tomato pasta"
+          "tomato-pasta"
         ]
         "###);
-        sl.delete_recipe("tomato pasta")?;
+        sl.delete_recipe(&RecipeId("tomato-pasta".to_string()));
         insta::assert_json_snapshot!(sl.recipes, @"[]");
         Ok(())
     }
 
+    #[test]
+    fn test_save_to_path() -> Result<(), Box<dyn std::error::Error>> {
+        let file = create_test_json_file()?;
+        let mut sl = ShoppingList::from_path(file.path())?;
+        sl.delete_recipe(&RecipeId("tomato-pasta".to_string()));
+        let dir = assert_fs::TempDir::new()?;
+        let path = dir.child("household").child("list.json");
+        std::fs::create_dir_all(dir.child("household").path())?;
+        sl.save(path.path())?;
+        let saved = ShoppingList::from_path(path.path())?;
+        insta::assert_json_snapshot!(saved.recipes, @"[]");
+        assert_eq!(saved.groceries.len(), sl.groceries.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_needed() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = crate::Groceries::new_initialized()?;
+        for name in ["olive oil", "garlic", "tahini", "basil"] {
+            g.add_item(GroceriesItem::new(name, "pantry"));
+        }
+        g.add_recipe("tomato pasta", "3 tbsp olive oil, 2 cloves garlic, basil")?;
+        g.add_recipe(
+            "hummus",
+            "1/4 cup olive oil, 1 garlic; crushed, 1/2 cup tahini",
+        )?;
+        g.add_recipe("salad", "2 tbsp olive oil")?;
+
+        let mut sl = ShoppingList::new();
+        sl.add_recipe(g.recipe("tomato pasta")?.id.clone());
+        sl.add_recipe(g.recipe("hummus")?.id.clone());
+        for item in &g.collection {
+            sl.add_groceries_item(item.clone());
+        }
+        let described: Vec<String> = sl
+            .groceries
+            .iter()
+            .map(|item| sl.describe(item, &g))
+            .collect();
+        insta::assert_json_snapshot!(described, @r###"
+        [
+          "olive oil — 7 tbsp (tomato pasta, hummus)",
+          "garlic — 2 cloves + 1 (tomato pasta, hummus)",
+          "tahini — 1/2 cup (hummus)",
+          "basil (tomato pasta)"
+        ]
+        "###);
+
+        // doubling hummus
+        let hummus = g.recipe("hummus")?.id.clone();
+        sl.set_servings(&hummus, 8, 4);
+        let described: Vec<String> = sl
+            .groceries
+            .iter()
+            .map(|item| sl.describe(item, &g))
+            .collect();
+        insta::assert_json_snapshot!(described, @r###"
+        [
+          "olive oil — 11 tbsp (tomato pasta, hummus)",
+          "garlic — 2 cloves + 2 (tomato pasta, hummus)",
+          "tahini — 1 cup (hummus)",
+          "basil (tomato pasta)"
+        ]
+        "###);
+        sl.set_servings(&hummus, 4, 4);
+        assert!(sl.servings.is_empty());
+
+        // changes to a recipe reach the lists it's on
+        g.edit_ingredient("tomato pasta", "basil", "1 bunch basil")?;
+        assert_eq!(
+            sl.describe(g.item("basil")?, &g),
+            "basil — 1 bunch (tomato pasta)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pantry() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = crate::Groceries::new_initialized()?;
+        for name in ["olive oil", "tahini", "chickpeas"] {
+            g.add_item(GroceriesItem::new(name, "pantry"));
+        }
+        g.add_recipe(
+            "hummus",
+            "2 tbsp olive oil, 1/2 cup tahini, 2 cans chickpeas",
+        )?;
+        g.fill_sections(&[]);
+        g.set_stock("olive oil", Some("1 cup".parse()?))?;
+        g.set_stock("tahini", Some("2 tbsp".parse()?))?;
+        g.set_stock("chickpeas", Some("1 can".parse()?))?;
+
+        let mut sl = ShoppingList::new();
+        sl.add_recipe(g.recipe("hummus")?.id.clone());
+        for item in &g.collection {
+            if !sl.in_pantry(item, &g) {
+                sl.add_groceries_item(item.clone());
+            }
+        }
+        let described: Vec<String> = sl
+            .groceries
+            .iter()
+            .map(|item| sl.describe(item, &g))
+            .collect();
+        insta::assert_json_snapshot!(described, @r###"
+        [
+          "tahini — 6 tbsp (hummus)",
+          "chickpeas — 1 can (hummus)"
+        ]
+        "###);
+
+        // what we bought goes in the pantry
+        sl.check_off("tahini", &mut g)?;
+        sl.check_off("chickpeas", &mut g)?;
+        assert_eq!(sl.missing().count(), 0);
+        // checking it off again doesn't buy it twice
+        sl.check_off("tahini", &mut g)?;
+        let on_hand: Vec<String> = g
+            .pantry()
+            .map(|item| format!("{} {}", item.name, item.on_hand.clone().unwrap_or_default()))
+            .collect();
+        assert_eq!(
+            on_hand,
+            ["olive oil 1 cup", "tahini 8 tbsp", "chickpeas 2 cans"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_shop() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{JsonStore, Store};
+
+        let dir = assert_fs::TempDir::new()?;
+        let open = || JsonStore::new(dir.child("groceries.json"), dir.child("list.json"));
+        let mut g = crate::Groceries::new_initialized()?;
+        for name in ["rice", "lemons", "tahini", "cumin", "kale", "feta"] {
+            g.add_item(GroceriesItem::new(name, "pantry"));
+        }
+        g.fill_sections(&[]);
+        let mut sl = ShoppingList::new();
+        for item in &g.collection {
+            sl.add_groceries_item(item.clone());
+        }
+        open().save_groceries(&g)?;
+        open().save_list(&sl)?;
+
+        // `list shop` saves after every item we get
+        let store = open();
+        for name in ["rice", "tahini", "cumin", "kale", "feta"] {
+            sl.check_off(name, &mut g)?;
+            store.save_list(&sl)?;
+            store.save_groceries(&g)?;
+        }
+        assert!(matches!(
+            sl.check_off("bread", &mut g),
+            Err(ReadError::ItemNotFound)
+        ));
+
+        let sl = store.load_list()?;
+        let purchased: Vec<&str> = sl.purchased.iter().map(|id| id.0.as_str()).collect();
+        assert_eq!(purchased, ["cumin", "feta", "kale", "rice", "tahini"]);
+        let missing: Vec<&str> = sl.missing().map(|item| item.name.0.as_str()).collect();
+        assert_eq!(missing, ["lemons"]);
+        assert!(store.load_groceries()?.item("feta")?.on_hand.is_some());
+        // the backups from before the trip are still there
+        assert_eq!(crate::backups(dir.child("list.json").path())?.len(), 1);
+        assert_eq!(crate::backups(dir.child("groceries.json").path())?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_names_on_the_list() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = crate::Groceries::new_initialized()?;
+        for name in ["Ritz Crackers", "cilantro", "kale", "limes"] {
+            g.add_item(GroceriesItem::new(name, "pantry"));
+        }
+        g.fill_sections(&[]);
+        g.add_alias("cilantro", "coriander")?;
+        let mut sl = ShoppingList::new();
+        for item in &g.collection {
+            sl.add_groceries_item(item.clone());
+        }
+
+        // as the list prints it, or by an alias
+        sl.check_off("ritz crackers", &mut g)?;
+        sl.check_off("coriander", &mut g)?;
+        let missing: Vec<&str> = sl.missing().map(|item| item.name.0.as_str()).collect();
+        assert_eq!(missing, ["kale", "limes"]);
+
+        sl.remove("RITZ CRACKERS", &g)?;
+        sl.remove("Coriander", &g)?;
+        sl.remove("kale", &g)?;
+        assert!(matches!(sl.remove("kale", &g), Err(ReadError::NotOnList)));
+        assert!(sl.purchased.is_empty());
+        let names: Vec<&str> = sl
+            .groceries
+            .iter()
+            .map(|item| item.name.0.as_str())
+            .collect();
+        assert_eq!(names, ["limes"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_recipe_from() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = crate::Groceries::new_initialized()?;
+        for name in ["chickpeas", "tahini", "cumin", "kale"] {
+            g.add_item(GroceriesItem::new(name, "pantry"));
+        }
+        g.add_recipe("hummus", "2 cans chickpeas, 1/2 cup tahini, cumin")?;
+        g.fill_sections(&[]);
+        g.set_stock("tahini", Some("1 cup".parse()?))?;
+        g.set_check_first("cumin", true)?;
+
+        let mut sl = ShoppingList::new();
+        sl.add_recipe_from(&g, "hummus")?;
+        assert!(matches!(
+            sl.add_recipe_from(&g, "falafel"),
+            Err(ReadError::RecipeNotFound)
+        ));
+        let names = |items: &[GroceriesItem]| {
+            items
+                .iter()
+                .map(|item| item.name.0.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(sl.recipes, [g.recipe("hummus")?.id.clone()]);
+        assert_eq!(names(&sl.groceries), ["chickpeas"]);
+        assert_eq!(names(&sl.checklist), ["cumin"]);
+
+        sl.remove("cumin", &g)?;
+        sl.remove("hummus", &g)?;
+        assert!(matches!(sl.remove("kale", &g), Err(ReadError::NotOnList)));
+        assert_eq!(names(&sl.groceries), ["chickpeas"]);
+        assert!(sl.recipes.is_empty() && sl.checklist.is_empty());
+
+        // the pantry check goes by the servings we want
+        let hummus = g.recipe("hummus")?.id.clone();
+        sl.set_servings(&hummus, 12, 4);
+        sl.add_recipe_from(&g, "hummus")?;
+        assert_eq!(names(&sl.groceries), ["chickpeas", "tahini"]);
+        sl.clear();
+        assert!(sl.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_items() -> Result<(), Box<dyn std::error::Error>> {
+        let mut g = crate::Groceries::new_initialized()?;
+        for name in ["parmigiana", "pasta", "Parmigiano", "parmesan"] {
+            g.add_item(GroceriesItem::new(name, "dairy"));
+        }
+        g.add_recipe("tomato pasta", "1 lb pasta, parmigiana")?;
+        g.add_recipe("risotto", "1 cup parmesan; grated, Parmigiano")?;
+        g.set_stock("parmesan", Some("100 g".parse()?))?;
+        g.set_stock("parmigiana", Some("50 g".parse()?))?;
+
+        let mut sl = ShoppingList::new();
+        sl.add_recipe(g.recipe("tomato pasta")?.id.clone());
+        sl.add_recipe(g.recipe("risotto")?.id.clone());
+        sl.add_groceries_item(g.item("Parmigiano")?.clone());
+        sl.add_checklist_item(g.item("parmesan")?.clone());
+        sl.purchased.insert(g.item("parmesan")?.id.clone());
+
+        assert!(matches!(
+            g.merge_items("parmigiana", &["parmigiana"]),
+            Err(ReadError::ParseInputError)
+        ));
+        let dupes = g.merge_items("parmigiana", &["Parmigiano", "parmesan"])?;
+        sl.merge_items(g.item("parmigiana")?, &dupes);
+
+        let names: Vec<&str> = g
+            .collection
+            .iter()
+            .map(|item| item.name.0.as_str())
+            .collect();
+        assert_eq!(names, ["parmigiana", "pasta"]);
+        assert_eq!(g.item("parmigiana")?.on_hand, Some("150 g".parse()?));
+        let described: Vec<String> = sl
+            .groceries
+            .iter()
+            .map(|item| sl.describe(item, &g))
+            .collect();
+        insta::assert_json_snapshot!(described, @r###"
+        [
+          "parmigiana — 1 cup (tomato pasta, risotto)"
+        ]
+        "###);
+        assert!(sl.checklist.is_empty());
+        assert!(sl.is_purchased(g.item("parmigiana")?));
+        Ok(())
+    }
+
     #[test]
     fn json_from_file() -> Result<(), Box<dyn std::error::Error>> {
         let file = create_test_json_file()?;
@@ -422,116 +956,66 @@ pub mod test {
 
         insta::assert_json_snapshot!(list, @r###"
         {
+          "schema_version": 3,
           "checklist": [],
           "recipes": [
-            "tomato pasta"
+            "tomato-pasta"
           ],
           "groceries": [
             {
+              "id": "garlic",
               "name": "garlic",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "crispy sheet-pan noodles",
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes",
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "tomatoes",
               "name": "tomatoes",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "basil",
               "name": "basil",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "lemons",
               "name": "lemons",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "chicken breasts with lemon",
-                "hummus",
-                "sheet-pan chicken with jammy tomatoes",
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "pasta",
               "name": "pasta",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "olive-oil",
               "name": "olive oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "sheet-pan chicken with jammy tomatoes",
-                "turkey meatballs",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "short-grain-brown-rice",
               "name": "short grain brown rice",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "flue flighter chicken stew"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "parmigiana",
               "name": "parmigiana",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "eggs",
               "name": "eggs",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies",
-                "fried eggs for breakfast",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "sausages",
               "name": "sausages",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "protein"
             },
             {
+              "id": "dumplings",
               "name": "dumplings",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             }
           ]
         }
@@ -545,251 +1029,150 @@ pub mod test {
         let mut list = ShoppingList::from_path(file.path())?;
         insta::assert_json_snapshot!(list, @r###"
         {
+          "schema_version": 3,
           "checklist": [],
           "recipes": [
-            "tomato pasta"
+            "tomato-pasta"
           ],
           "groceries": [
             {
+              "id": "garlic",
               "name": "garlic",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "crispy sheet-pan noodles",
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes",
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "tomatoes",
               "name": "tomatoes",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "basil",
               "name": "basil",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "lemons",
               "name": "lemons",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "chicken breasts with lemon",
-                "hummus",
-                "sheet-pan chicken with jammy tomatoes",
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "pasta",
               "name": "pasta",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "olive-oil",
               "name": "olive oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "sheet-pan chicken with jammy tomatoes",
-                "turkey meatballs",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "short-grain-brown-rice",
               "name": "short grain brown rice",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "flue flighter chicken stew"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "parmigiana",
               "name": "parmigiana",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "eggs",
               "name": "eggs",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies",
-                "fried eggs for breakfast",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "sausages",
               "name": "sausages",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "protein"
             },
             {
+              "id": "dumplings",
               "name": "dumplings",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             }
           ]
         }
         "###);
 
         let item = GroceriesItem {
+            id: ItemId("cumquats".to_string()),
             name: crate::GroceriesItemName("cumquats".to_string()),
             section: crate::GroceriesItemSection("fresh".to_string()),
-            is_recipe_ingredient: true,
-            recipes: vec![Recipe("cumquat chutney".to_string())],
+            aliases: vec![],
+            on_hand: None,
+            check_first: false,
         };
-        let recipe = Recipe("cumquat chutney".to_string());
+        let recipe = RecipeRecord::new(crate::RecipeName("cumquat chutney".to_string()));
         list.add_groceries_item(item);
-        list.add_recipe(recipe);
+        list.add_recipe(recipe.id);
         insta::assert_json_snapshot!(list, @r###"
         {
+          "schema_version": 3,
           "checklist": [],
           "recipes": [
-            "tomato pasta",
-            "cumquat chutney"
+            "tomato-pasta",
+            "cumquat-chutney"
           ],
           "groceries": [
             {
+              "id": "garlic",
               "name": "garlic",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "crispy tofu with cashews and blistered snap peas",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "crispy sheet-pan noodles",
-                "flue flighter chicken stew",
-                "sheet-pan chicken with jammy tomatoes",
-                "swordfish pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "tomatoes",
               "name": "tomatoes",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "basil",
               "name": "basil",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "lemons",
               "name": "lemons",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "chicken breasts with lemon",
-                "hummus",
-                "sheet-pan chicken with jammy tomatoes",
-                "flue flighter chicken stew"
-              ]
+              "section": "fresh"
             },
             {
+              "id": "pasta",
               "name": "pasta",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "olive-oil",
               "name": "olive oil",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "chicken breasts with lemon",
-                "hummus",
-                "tomato pasta",
-                "sheet-pan chicken with jammy tomatoes",
-                "turkey meatballs",
-                "swordfish pasta"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "short-grain-brown-rice",
               "name": "short grain brown rice",
-              "section": "pantry",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "Sheet Pan Salmon with Broccoli",
-                "flue flighter chicken stew"
-              ]
+              "section": "pantry"
             },
             {
+              "id": "parmigiana",
               "name": "parmigiana",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "tomato pasta",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "eggs",
               "name": "eggs",
-              "section": "dairy",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "oatmeal chocolate chip cookies",
-                "fried eggs for breakfast",
-                "turkey meatballs"
-              ]
+              "section": "dairy"
             },
             {
+              "id": "sausages",
               "name": "sausages",
-              "section": "protein",
-              "is_recipe_ingredient": true,
-              "recipes": []
+              "section": "protein"
             },
             {
+              "id": "dumplings",
               "name": "dumplings",
-              "section": "freezer",
-              "is_recipe_ingredient": false,
-              "recipes": []
+              "section": "freezer"
             },
             {
+              "id": "cumquats",
               "name": "cumquats",
-              "section": "fresh",
-              "is_recipe_ingredient": true,
-              "recipes": [
-                "cumquat chutney"
-              ]
+              "section": "fresh"
             }
           ]
         }
@@ -1,10 +1,110 @@
-pub fn run() -> Result<(), crate::ReadError> {
-    let matches = crate::cli::cli().get_matches();
+use crate::{Config, JsonStore, ReadError, SqliteStore, Store};
+use clap::ArgMatches;
+
+pub fn run() -> Result<(), ReadError> {
+    let config = Config::load()?;
+    let matches = crate::cli::cli(&config).get_matches();
 
     match matches.subcommand() {
-        Some(("recipes", sync_matches)) => Ok(crate::run_recipes::run(sync_matches)?),
-        Some(("groceries", _sync_matches)) => Ok(crate::run_groceries::run()?),
-        Some(("list", _sync_matches)) => Ok(crate::run_shopping_list::run()?),
+        Some(("recipes", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let store = open_store(&matches, &config, path, &config.list)?;
+            Ok(crate::run_recipes::run(sync_matches, store.as_ref())?)
+        }
+        Some(("groceries", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let list_path = sync_matches.get_one::<String>("list path").unwrap();
+            let store = open_store(&matches, &config, path, list_path)?;
+            Ok(crate::run_groceries::run(
+                sync_matches,
+                store.as_ref(),
+                &config,
+            )?)
+        }
+        Some(("sections", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let store = open_store(&matches, &config, path, &config.list)?;
+            Ok(crate::run_sections::run(
+                sync_matches,
+                store.as_ref(),
+                &config,
+            )?)
+        }
+        Some(("pantry", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let list_path = sync_matches.get_one::<String>("list path").unwrap();
+            let store = open_store(&matches, &config, path, list_path)?;
+            Ok(crate::run_pantry::run(sync_matches, store.as_ref())?)
+        }
+        Some(("stores", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let store = open_store(&matches, &config, path, &config.list)?;
+            Ok(crate::run_stores::run(
+                sync_matches,
+                store.as_ref(),
+                &config,
+            )?)
+        }
+        Some(("list", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let lib_path = sync_matches.get_one::<String>("library path").unwrap();
+            let store = open_store(&matches, &config, lib_path, path)?;
+            Ok(crate::run_shopping_list::run(
+                sync_matches,
+                store.as_ref(),
+                &config,
+            )?)
+        }
+        Some(("migrate", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let lib_path = sync_matches.get_one::<String>("library path").unwrap();
+            let store = open_store(&matches, &config, lib_path, path)?;
+            Ok(crate::run_migrate::run(sync_matches, store.as_ref())?)
+        }
+        Some(("doctor", sync_matches)) => {
+            let path = sync_matches.get_one::<String>("path").unwrap();
+            let list_path = sync_matches.get_one::<String>("list path").unwrap();
+            let store = open_store(&matches, &config, path, list_path)?;
+            Ok(crate::run_doctor::run(sync_matches, store.as_ref())?)
+        }
+        Some(("restore", sync_matches)) => {
+            // a SQLite store keeps everything in the one database file
+            let path = match matches.get_one::<String>("store").map(String::as_str) {
+                Some("sqlite") => matches.get_one::<String>("db path").unwrap(),
+                _ => sync_matches.get_one::<String>("path").unwrap(),
+            };
+            Ok(crate::run_restore::run(
+                sync_matches,
+                path,
+                backups(&matches, &config),
+            )?)
+        }
         _ => unreachable!(),
     }
 }
+
+fn backups(matches: &ArgMatches, config: &Config) -> usize {
+    matches
+        .get_one::<usize>("backups")
+        .copied()
+        .unwrap_or(config.backups)
+}
+
+fn open_store(
+    matches: &ArgMatches,
+    config: &Config,
+    groceries_path: &str,
+    list_path: &str,
+) -> Result<Box<dyn Store>, ReadError> {
+    match matches.get_one::<String>("store").map(String::as_str) {
+        Some("sqlite") => {
+            let db_path = matches.get_one::<String>("db path").unwrap();
+            Ok(Box::new(
+                SqliteStore::open(db_path)?.with_backups(backups(matches, config)),
+            ))
+        }
+        _ => Ok(Box::new(
+            JsonStore::new(groceries_path, list_path).with_backups(backups(matches, config)),
+        )),
+    }
+}
@@ -0,0 +1,366 @@
+use crate::{
+    backup, read, schema_version, Groceries, ReadError, RecipeRecord, ShoppingList,
+    DEFAULT_BACKUPS, LIST_SCHEMA_VERSION,
+};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// Somewhere to keep the groceries library, its recipes and the shopping list.
+///
+/// Stores keep two documents, the library and the list, and load and save
+/// each whole. Recipes are part of the library document, so they are saved
+/// with it rather than on their own.
+///
+/// A store backs up a document the first time it saves it and not after,
+/// so a command that saves as it goes, like `list shop`, leaves one backup
+/// of how things were before it started rather than pushing out the others.
+pub trait Store {
+    fn has_groceries(&self) -> Result<bool, ReadError>;
+
+    /// The library as saved, before any schema migrations.
+    fn load_groceries_document(&self) -> Result<Value, ReadError>;
+
+    fn load_groceries(&self) -> Result<Groceries, ReadError> {
+        Groceries::from_document(self.load_groceries_document()?)
+    }
+
+    fn save_groceries(&self, groceries: &Groceries) -> Result<(), ReadError>;
+
+    /// The library's recipes, which are loaded with the rest of it.
+    fn load_recipes(&self) -> Result<Vec<RecipeRecord>, ReadError> {
+        Ok(self.load_groceries()?.recipes)
+    }
+
+    fn has_list(&self) -> Result<bool, ReadError>;
+
+    /// The shopping list as saved, before any schema migrations.
+    fn load_list_document(&self) -> Result<Value, ReadError>;
+
+    /// The shopping list, migrated with the ids the library has.
+    fn load_list(&self) -> Result<ShoppingList, ReadError> {
+        let document = self.load_list_document()?;
+        let library = if schema_version(&document) < LIST_SCHEMA_VERSION && self.has_groceries()? {
+            Some(self.load_groceries()?)
+        } else {
+            None
+        };
+        ShoppingList::from_document(document, library.as_ref())
+    }
+
+    fn save_list(&self, list: &ShoppingList) -> Result<(), ReadError>;
+}
+
+/// Keeps the library and the shopping list in two JSON files.
+#[derive(Debug, Clone)]
+pub struct JsonStore {
+    groceries: PathBuf,
+    list: PathBuf,
+    backups: usize,
+    groceries_backed_up: Cell<bool>,
+    list_backed_up: Cell<bool>,
+}
+
+impl JsonStore {
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(groceries: P, list: Q) -> Self {
+        JsonStore {
+            groceries: groceries.as_ref().to_path_buf(),
+            list: list.as_ref().to_path_buf(),
+            backups: DEFAULT_BACKUPS,
+            groceries_backed_up: Cell::new(false),
+            list_backed_up: Cell::new(false),
+        }
+    }
+
+    /// Sets how many backups of each file to keep, `0` for none.
+    pub fn with_backups(mut self, backups: usize) -> Self {
+        self.backups = backups;
+        self
+    }
+}
+
+impl Store for JsonStore {
+    fn has_groceries(&self) -> Result<bool, ReadError> {
+        Ok(self.groceries.exists())
+    }
+
+    fn load_groceries_document(&self) -> Result<Value, ReadError> {
+        Ok(serde_json::from_reader(read(&self.groceries)?)?)
+    }
+
+    fn save_groceries(&self, groceries: &Groceries) -> Result<(), ReadError> {
+        backup_once(&self.groceries, self.backups, &self.groceries_backed_up)?;
+        groceries.save(&self.groceries)
+    }
+
+    fn has_list(&self) -> Result<bool, ReadError> {
+        Ok(self.list.exists())
+    }
+
+    fn load_list_document(&self) -> Result<Value, ReadError> {
+        Ok(serde_json::from_reader(read(&self.list)?)?)
+    }
+
+    fn save_list(&self, list: &ShoppingList) -> Result<(), ReadError> {
+        backup_once(&self.list, self.backups, &self.list_backed_up)?;
+        list.save(&self.list)
+    }
+}
+
+// backs up `path` unless `backed_up` says we have already
+fn backup_once(path: &Path, keep: usize, backed_up: &Cell<bool>) -> Result<(), ReadError> {
+    if !backed_up.get() {
+        backup(path, keep)?;
+        backed_up.set(true);
+    }
+    Ok(())
+}
+
+const GROCERIES: &str = "groceries";
+const LIST: &str = "list";
+
+/// Keeps the library and the shopping list in an embedded SQLite database.
+///
+/// This is document storage, not a relational schema: the database holds
+/// the same JSON documents a [`JsonStore`] writes to files. Each top-level
+/// field of a document is a row in `fields`, and every element of a list
+/// field (each item, recipe, section, ...) is a JSON row in `entries`, so
+/// saving only touches the rows that changed. Items, recipes and their
+/// ingredients aren't tables of their own and can't be queried as such.
+pub struct SqliteStore {
+    conn: Connection,
+    path: PathBuf,
+    backups: usize,
+    backed_up: Cell<bool>,
+}
+
+impl SqliteStore {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ReadError> {
+        let path = path.as_ref().to_path_buf();
+        crate::helpers::create_parent(&path)?;
+        let conn = Connection::open(&path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS documents (
+                name TEXT PRIMARY KEY
+            );
+            CREATE TABLE IF NOT EXISTS fields (
+                document TEXT NOT NULL,
+                field TEXT NOT NULL,
+                data TEXT,
+                PRIMARY KEY (document, field)
+            );
+            CREATE TABLE IF NOT EXISTS entries (
+                document TEXT NOT NULL,
+                field TEXT NOT NULL,
+                position INTEGER NOT NULL,
+                data TEXT NOT NULL,
+                PRIMARY KEY (document, field, position)
+            );",
+        )?;
+        Ok(SqliteStore {
+            conn,
+            path,
+            backups: DEFAULT_BACKUPS,
+            backed_up: Cell::new(false),
+        })
+    }
+
+    /// Sets how many backups of the database to keep, `0` for none.
+    pub fn with_backups(mut self, backups: usize) -> Self {
+        self.backups = backups;
+        self
+    }
+
+    fn has_document(&self, document: &str) -> Result<bool, ReadError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT 1 FROM documents WHERE name = ?1",
+                [document],
+                |_| Ok(()),
+            )
+            .optional()?
+            .is_some())
+    }
+
+    fn load_document(&self, document: &str) -> Result<Value, ReadError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT field, data FROM fields WHERE document = ?1")?;
+        let fields = stmt
+            .query_map([document], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<(String, Option<String>)>, _>>()?;
+
+        let mut object = Map::new();
+        for (field, data) in fields {
+            // list fields have no data of their own, their elements are in `entries`
+            let value = match data {
+                Some(data) => serde_json::from_str(&data)?,
+                None => Value::Array(self.load_entries(document, &field)?),
+            };
+            object.insert(field, value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn load_entries(&self, document: &str, field: &str) -> Result<Vec<Value>, ReadError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT data FROM entries WHERE document = ?1 AND field = ?2 ORDER BY position",
+        )?;
+        let entries = stmt
+            .query_map([document, field], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        entries
+            .iter()
+            .map(|data| Ok(serde_json::from_str(data)?))
+            .collect()
+    }
+
+    fn save_document<T: Serialize>(&self, document: &str, object: &T) -> Result<(), ReadError> {
+        let fields = match serde_json::to_value(object)? {
+            Value::Object(fields) => fields,
+            _ => return Err(ReadError::ParseInputError),
+        };
+
+        backup_once(&self.path, self.backups, &self.backed_up)?;
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT OR IGNORE INTO documents (name) VALUES (?1)",
+            [document],
+        )?;
+        tx.execute("DELETE FROM fields WHERE document = ?1", [document])?;
+        for (field, value) in fields.iter() {
+            match value {
+                Value::Array(elements) => {
+                    tx.execute(
+                        "INSERT INTO fields (document, field, data) VALUES (?1, ?2, NULL)",
+                        [document, field],
+                    )?;
+                    Self::save_entries(&tx, document, field, elements)?;
+                }
+                _ => {
+                    tx.execute(
+                        "INSERT INTO fields (document, field, data) VALUES (?1, ?2, ?3)",
+                        [document, field, &value.to_string()],
+                    )?;
+                }
+            }
+        }
+        tx.execute(
+            "DELETE FROM entries WHERE document = ?1 AND field NOT IN
+                (SELECT field FROM fields WHERE document = ?1 AND data IS NULL)",
+            [document],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn save_entries(
+        tx: &Transaction,
+        document: &str,
+        field: &str,
+        elements: &[Value],
+    ) -> Result<(), ReadError> {
+        let mut stmt =
+            tx.prepare("SELECT position, data FROM entries WHERE document = ?1 AND field = ?2")?;
+        let saved = stmt
+            .query_map([document, field], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<HashMap<i64, String>, _>>()?;
+
+        for (position, element) in elements.iter().enumerate() {
+            let data = element.to_string();
+            if saved.get(&(position as i64)) != Some(&data) {
+                tx.execute(
+                    "INSERT OR REPLACE INTO entries (document, field, position, data)
+                        VALUES (?1, ?2, ?3, ?4)",
+                    params![document, field, position as i64, data],
+                )?;
+            }
+        }
+        tx.execute(
+            "DELETE FROM entries WHERE document = ?1 AND field = ?2 AND position >= ?3",
+            params![document, field, elements.len() as i64],
+        )?;
+        Ok(())
+    }
+}
+
+impl Store for SqliteStore {
+    fn has_groceries(&self) -> Result<bool, ReadError> {
+        self.has_document(GROCERIES)
+    }
+
+    fn load_groceries_document(&self) -> Result<Value, ReadError> {
+        if !self.has_groceries()? {
+            return Err(ReadError::LibraryNotFound);
+        }
+        self.load_document(GROCERIES)
+    }
+
+    fn save_groceries(&self, groceries: &Groceries) -> Result<(), ReadError> {
+        self.save_document(GROCERIES, groceries)
+    }
+
+    fn has_list(&self) -> Result<bool, ReadError> {
+        self.has_document(LIST)
+    }
+
+    fn load_list_document(&self) -> Result<Value, ReadError> {
+        if !self.has_list()? {
+            return Err(ReadError::ListNotFound);
+        }
+        self.load_document(LIST)
+    }
+
+    fn save_list(&self, list: &ShoppingList) -> Result<(), ReadError> {
+        self.save_document(LIST, list)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::GroceriesItem;
+    use assert_fs::prelude::*;
+
+    #[test]
+    fn test_sqlite_store_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = assert_fs::TempDir::new()?;
+        let store = SqliteStore::open(dir.child("test.db").path())?;
+        assert!(!store.has_groceries()?);
+        assert!(!store.has_list()?);
+
+        let mut g = Groceries::new_initialized()?;
+        g.add_item(GroceriesItem::new("garlic", "fresh"));
+        g.add_item(GroceriesItem::new("pasta", "pantry"));
+        g.add_recipe("tomato pasta", "garlic, pasta")?;
+        store.save_groceries(&g)?;
+        assert_eq!(store.load_groceries()?, g);
+
+        g.delete_item("garlic")?;
+        store.save_groceries(&g)?;
+        assert_eq!(store.load_groceries()?, g);
+
+        let mut sl = ShoppingList::new();
+        sl.add_recipe(g.recipe("tomato pasta")?.id.clone());
+        sl.add_groceries_item(GroceriesItem::new("pasta", "pantry"));
+        store.save_list(&sl)?;
+        assert_eq!(store.load_list()?.to_json_string()?, sl.to_json_string()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_store_missing_files() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = assert_fs::TempDir::new()?;
+        let store = JsonStore::new(dir.child("groceries.json"), dir.child("list.json"));
+        assert!(!store.has_groceries()?);
+        assert!(!store.has_list()?);
+        Ok(())
+    }
+}